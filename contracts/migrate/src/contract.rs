@@ -157,6 +157,8 @@ pub fn execute_redeem_mint(
             denom: state.tf_denom,
             amount,
         }],
+        mint_id: None,
+        memo: None,
     };
 
     Ok(Response::new()
@@ -57,6 +57,8 @@ pub fn execute(
             let payload = Mint {
                 address: to_address,
                 denom: denoms,
+                mint_id: None,
+                memo: None,
             };
             let wasm_msg = WasmMsg::Execute {
                 contract_addr: core_tf_addr.to_string(),
@@ -1,10 +1,12 @@
 use cosmwasm_schema::write_api;
-use juno_tokenfactory_core::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use juno_tokenfactory_core::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        migrate: MigrateMsg,
+        sudo: SudoMsg,
     }
 }
@@ -1,18 +1,43 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, AllBalanceResponse, BalanceResponse, BankMsg, BankQuery, Binary, Coin, Deps,
-    DepsMut, Env, MessageInfo, Response, StdResult,
+    to_binary, to_vec, Addr, AllBalanceResponse, BalanceResponse, BankMsg, BankQuery, Binary, Coin,
+    Deps, DepsMut, Empty, Env, Event, MessageInfo, Order, Reply, Response, StdError, StdResult,
+    SubMsg, Uint128, WasmMsg,
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Bound;
+use cw_utils::Expiration;
+use semver::Version;
+use sha2::{Digest, Sha256};
 
 use crate::error::ContractError;
 use crate::helpers::{
-    create_denom_msg, is_contract_manager, is_whitelisted, mint_factory_token_messages,
-    mint_tokens_msg, pretty_denoms_output,
+    add_collateral, add_label_attribute, add_minted_total, aggregate_coins, check_max_mint_per_tx,
+    check_max_supply, check_mint_allowance, clear_denom_state, collect_mint_funds,
+    consume_mint_allowance, create_denom_msg, ensure_contract_address, ensure_mintable,
+    ensure_no_collateral, ensure_unique, is_contract_manager, is_denom_manager, is_managed_denom,
+    is_whitelisted, load_config, mint_allowance, mint_display, mint_factory_token_messages,
+    mint_tokens_msg, pretty_denoms_output, query_denom_admin, query_denom_metadata,
+    release_collateral, remove_whitelist_entry, save_whitelist_entry, set_before_send_hook_msg,
+    stage_denom_minter, stage_whitelist_entry, sum_amounts, validate_address, validate_addresses,
+    validate_label, validate_subdenom, MAX_BATCH_SIZE, MAX_MEMO_LENGTH, MAX_MINT_ID_LENGTH,
+    MAX_NONCE_LENGTH,
+};
+use crate::migrations::migrate_legacy_config;
+use crate::msg::{
+    BatchMint, BurnResponse, ConfigField, ConfigFieldsResponse, CreateDenomResponse, Denom,
+    DenomStatus, DenomsByOriginResponse, ExecuteMsg, FullConfigResponse, InstantiateMsg,
+    IsDenomMintableResponse, IsManagedDenomResponse, IsPausedResponse, IsWhitelistedResponse,
+    MigrateMsg, MintAllowanceResponse, MintResponse, Ownership, OwnershipAction, QueryMsg,
+    SimulateBurnResponse, SimulateMintResponse, StatsResponse, SudoMsg, Voucher,
+};
+use crate::state::{
+    Config, PendingManager, WhitelistEntry, BLOCKLIST, COLLATERAL, CONFIG, DENOM_MANAGERS,
+    DENOM_MINTERS, DISABLED_DENOMS, MAX_MINT_PER_TX, MAX_SUPPLY, MINTED_TOTALS, MINT_IDS,
+    MINT_WINDOWS, PENDING_DENOM_MINTERS, PENDING_MANAGER, PENDING_WHITELIST, RATE_LIMITS,
+    USED_VOUCHERS, WHITELIST, WHITELIST_COUNT,
 };
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Config, CONFIG};
 
 use token_bindings::TokenFactoryMsg;
 
@@ -20,6 +45,13 @@ use token_bindings::TokenFactoryMsg;
 const CONTRACT_NAME: &str = "crates.io:tokenfactory-core";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// reply ids
+const CREATE_DENOM_REPLY_ID: u64 = 1;
+
+// pagination
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -29,16 +61,9 @@ pub fn instantiate(
 ) -> Result<Response<TokenFactoryMsg>, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    // Validate existing denoms.
+    // Existing denoms are validated when the message is parsed
     let mut denoms = msg.existing_denoms.unwrap_or_default();
-    for d in denoms.iter() {
-        if !d.starts_with("factory/") {
-            return Err(ContractError::InvalidDenom {
-                denom: d.clone(),
-                message: "Denom must start with 'factory/'".to_string(),
-            });
-        }
-    }
+    ensure_unique(&denoms)?;
 
     // Create new denoms.
     let mut new_denom_msgs: Vec<TokenFactoryMsg> = vec![];
@@ -48,6 +73,7 @@ pub fn instantiate(
         if !new_denoms.is_empty() {
             for denom in new_denoms {
                 let subdenom = denom.symbol.to_lowercase();
+                validate_subdenom(&subdenom)?;
                 let full_denom = format!("factory/{}/{}", env.contract.address, subdenom);
 
                 // Add creation message.
@@ -62,10 +88,11 @@ pub fn instantiate(
                     if !initial_balances.is_empty() {
                         // Validate addresses.
                         for initial in initial_balances.iter() {
-                            deps.api.addr_validate(&initial.address)?;
+                            validate_address(deps.api, "initial_balances", &initial.address)?;
                         }
 
                         for b in initial_balances {
+                            add_minted_total(deps.storage, &full_denom, b.amount)?;
                             new_mint_msgs.push(mint_tokens_msg(
                                 b.address.clone(),
                                 full_denom.clone(),
@@ -76,34 +103,139 @@ pub fn instantiate(
                 }
 
                 // Add to existing denoms.
-                denoms.push(full_denom);
+                denoms.push(Denom::try_new(full_denom)?);
             }
         }
     }
 
-    if denoms.is_empty() {
-        return Err(ContractError::NoDenomsProvided {});
+    // Starting with no denoms is allowed, they can be added later with AddDenom or CreateDenom.
+    // Until then minting is rejected and burn returns all funds.
+    // New denoms are checked too, so a symbol matching an existing denom is caught.
+    ensure_unique(&denoms)?;
+
+    // Sent after the denoms are created, so new denoms can be given metadata too
+    let denom_metadata = msg.denom_metadata.unwrap_or_default();
+    let metadata_denoms: Vec<&String> = denom_metadata.iter().map(|(d, _)| d).collect();
+    ensure_unique(&metadata_denoms)?;
+    let mut metadata_msgs: Vec<TokenFactoryMsg> = vec![];
+    for (denom, metadata) in denom_metadata {
+        if !denoms.iter().any(|d| *d == denom) {
+            return Err(ContractError::InvalidDenom {
+                denom,
+                message: "Metadata can only be set for the denoms in this message".to_string(),
+            });
+        }
+        if let Some(base) = &metadata.base {
+            if *base != denom {
+                return Err(ContractError::InvalidDenom {
+                    denom,
+                    message: format!("Metadata base {base} must match the denom"),
+                });
+            }
+        }
+        metadata_msgs.push(TokenFactoryMsg::SetMetadata { denom, metadata });
     }
 
-    let manager = deps
-        .api
-        .addr_validate(&msg.manager.unwrap_or_else(|| _info.sender.to_string()))?;
+    if msg.manager.as_ref().is_some_and(|m| m.trim().is_empty()) {
+        return Err(ContractError::EmptyManager {});
+    }
+    let manager = validate_address(
+        deps.api,
+        "manager",
+        &msg.manager.unwrap_or_else(|| _info.sender.to_string()),
+    )?;
+
+    let allowed = msg
+        .allowed_mint_addresses
+        .iter()
+        .map(|a| validate_address(deps.api, "allowed_mint_addresses", a))
+        .collect::<Result<Vec<String>, ContractError>>()?;
+    ensure_unique(&allowed)?;
+    let whitelist_count = allowed.len();
+    for address in allowed {
+        save_whitelist_entry(
+            deps.storage,
+            &Addr::unchecked(address),
+            &WhitelistEntry::default(),
+        )?;
+    }
+
+    let denom_minters = msg.denom_minters.unwrap_or_default();
+    let minter_denoms: Vec<&String> = denom_minters.iter().map(|(d, _)| d).collect();
+    ensure_unique(&minter_denoms)?;
+    for (denom, minters) in denom_minters {
+        if !denoms.iter().any(|d| *d == denom) {
+            return Err(ContractError::InvalidDenom {
+                denom,
+                message: "Minters can only be set for the denoms in this message".to_string(),
+            });
+        }
+        // duplicates are rejected here like everywhere else in instantiate, not merged
+        let minters = minters
+            .iter()
+            .map(|m| validate_address(deps.api, "denom_minters", m))
+            .collect::<Result<Vec<String>, ContractError>>()?;
+        ensure_unique(&minters)?;
+        // whitelist approval always starts disabled, so these are granted directly like the whitelist
+        for minter in minters {
+            DENOM_MINTERS.save(
+                deps.storage,
+                (denom.as_str(), &Addr::unchecked(minter)),
+                &Empty {},
+            )?;
+        }
+    }
+
+    let treasury = match msg.treasury {
+        Some(treasury) => Some(validate_address(deps.api, "treasury", &treasury)?),
+        None => None,
+    };
+
+    if let Some(label) = &msg.label {
+        validate_label(label)?;
+    }
 
     let config = Config {
-        manager: manager.to_string(),
-        allowed_mint_addresses: msg.allowed_mint_addresses,
+        managers: vec![manager.to_string()],
         denoms,
+        paused: false,
+        treasury,
+        renounced: false,
+        verify_denom_admin: msg.verify_denom_admin.unwrap_or(false),
+        mint_fee: None,
+        strict_burn: msg.strict_burn.unwrap_or(false),
+        collateral_denom: None,
+        whitelist_approval: false,
+        label: msg.label,
+        voucher_signer: None,
     };
     CONFIG.save(deps.storage, &config)?;
 
-    Ok(Response::new()
+    // the initial state, so indexers do not have to query it
+    let res = Response::new()
         .add_attribute("method", "instantiate")
+        .add_attribute("manager", config.managers.join(","))
+        .add_attribute("denom_count", config.denoms.len().to_string())
+        .add_attribute("whitelist_count", whitelist_count.to_string())
         .add_messages(new_denom_msgs)
-        .add_messages(new_mint_msgs))
+        .add_messages(new_mint_msgs)
+        .add_messages(metadata_msgs);
+    add_label_attribute(deps.storage, res)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    // handlers do not know about the label, it is added to whatever they respond with
+    let res = execute_msg(deps.branch(), env, info, msg)?;
+    add_label_attribute(deps.storage, res)
+}
+
+fn execute_msg(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
@@ -114,69 +246,104 @@ pub fn execute(
         ExecuteMsg::Burn {} => execute_burn(deps, env, info),
 
         // == WHITELIST ==
-        ExecuteMsg::Mint { address, denom } => execute_mint(deps, info, address, denom),
+        ExecuteMsg::Mint {
+            address,
+            denom,
+            mint_id,
+            memo,
+        } => execute_mint(deps, env, info, address, denom, mint_id, memo),
+        ExecuteMsg::MintToSelf { denom } => {
+            let address = env.contract.address.to_string();
+            execute_mint(deps, env, info, address, vec![denom], None, None)
+        }
+        ExecuteMsg::MintAndCall {
+            contract,
+            denom,
+            msg,
+        } => execute_mint_and_call(deps, env, info, contract, denom, msg),
+        ExecuteMsg::MintBatch { mints } => execute_mint_batch(deps, env, info, mints),
+        ExecuteMsg::MintWithVoucher {
+            recipient,
+            denom,
+            amount,
+            expiry,
+            nonce,
+            signature,
+        } => {
+            let voucher = Voucher {
+                contract: env.contract.address.to_string(),
+                recipient,
+                denom: denom.to_string(),
+                amount,
+                expiry,
+                nonce,
+            };
+            execute_mint_with_voucher(deps, env, info, voucher, signature)
+        }
 
         // == MANAGER ==
-        ExecuteMsg::BurnFrom { from, denom } => {
-            let config = CONFIG.load(deps.storage)?;
-            is_contract_manager(config, info.sender)?;
-
-            let balance = deps.querier.query_all_balances(from.clone())?;
+        ExecuteMsg::CreateDenom { subdenom } => execute_create_denom(deps, env, info, subdenom),
+        ExecuteMsg::EnsureDenoms { subdenoms } => execute_ensure_denoms(deps, env, info, subdenoms),
+        ExecuteMsg::CreateAndMint {
+            subdenom,
+            initial_mint,
+        } => execute_create_and_mint(deps, env, info, subdenom, initial_mint),
 
-            let mut found = false;
-            for coin in balance.iter() {
-                if coin.denom == denom.denom {
-                    found = true;
-                }
-            }
+        ExecuteMsg::BurnFrom { from, denom } => execute_burn_from(deps, info, from, denom),
 
-            if !found {
-                return Err(ContractError::InvalidDenom {
-                    denom: denom.denom,
-                    message: "Denom not found in balance".to_string(),
-                });
-            }
+        ExecuteMsg::TransferAdmin { denom, new_address } => {
+            execute_transfer_admin(deps, info, denom, new_address)
+        }
 
-            // burn from from_address
-            let msg: TokenFactoryMsg = TokenFactoryMsg::BurnTokens {
-                denom: denom.denom.clone(),
-                amount: denom.amount,
-                burn_from_address: from,
-            };
+        ExecuteMsg::ReclaimAdmin { denom } => execute_reclaim_admin(deps, env, info, denom),
 
-            Ok(Response::new()
-                .add_attribute("method", "execute_burn_from")
-                .add_attribute("denom", denom.denom)
-                .add_message(msg))
+        ExecuteMsg::TransferAdminBatch { transfers } => {
+            execute_transfer_admin_batch(deps, info, transfers)
         }
-
-        ExecuteMsg::TransferAdmin { denom, new_address } => {
-            execute_transfer_admin(deps, info, denom, new_address)
+        ExecuteMsg::RelinquishAll { new_admin, pause } => {
+            execute_relinquish_all(deps, info, new_admin, pause.unwrap_or(false))
         }
 
         ExecuteMsg::ForceTransfer { from, to, denom } => {
-            let config = CONFIG.load(deps.storage)?;
-            is_contract_manager(config, info.sender)?;
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+            is_managed_denom(&config, &denom.denom)?;
+
+            let from = validate_address(deps.api, "from", &from)?;
+            let to = validate_address(deps.api, "to", &to)?;
 
             let msg: TokenFactoryMsg = TokenFactoryMsg::ForceTransfer {
                 denom: denom.denom.clone(),
                 amount: denom.amount,
-                from_address: from,
-                to_address: to,
+                from_address: from.clone(),
+                to_address: to.clone(),
             };
 
             Ok(Response::new()
                 .add_attribute("method", "execute_force_transfer")
                 .add_attribute("denom", denom.denom)
+                .add_attribute("amount", denom.amount)
+                .add_attribute("from", from)
+                .add_attribute("to", to)
                 .add_message(msg))
         }
 
         ExecuteMsg::SetMetadata { denom, metadata } => {
-            let config = CONFIG.load(deps.storage)?;
-            is_contract_manager(config, info.sender)?;
+            let config = load_config(deps.storage)?;
+            is_managed_denom(&config, denom.as_str())?;
+            is_denom_manager(deps.storage, config, denom.as_str(), info.sender)?;
+
+            if let Some(base) = &metadata.base {
+                if denom != *base {
+                    return Err(ContractError::InvalidDenom {
+                        denom: denom.to_string(),
+                        message: format!("Metadata base {base} must match the denom"),
+                    });
+                }
+            }
 
             let msg: TokenFactoryMsg = TokenFactoryMsg::SetMetadata {
-                denom: denom.clone(),
+                denom: denom.to_string(),
                 metadata,
             };
 
@@ -186,51 +353,386 @@ pub fn execute(
                 .add_message(msg))
         }
 
+        ExecuteMsg::SetBeforeSendHook {
+            denom,
+            cosmwasm_address,
+        } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+            is_managed_denom(&config, denom.as_str())?;
+
+            let hook =
+                ensure_contract_address(deps.as_ref(), "cosmwasm_address", &cosmwasm_address)?;
+            let msg = set_before_send_hook_msg(
+                env.contract.address.to_string(),
+                denom.to_string(),
+                hook.clone(),
+            );
+
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_before_send_hook")
+                .add_attribute("denom", denom)
+                .add_attribute("cosmwasm_address", hook)
+                .add_message(msg))
+        }
+        ExecuteMsg::ClearBeforeSendHook { denom } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+            is_managed_denom(&config, denom.as_str())?;
+
+            let msg = set_before_send_hook_msg(
+                env.contract.address.to_string(),
+                denom.to_string(),
+                "".to_string(),
+            );
+
+            Ok(Response::new()
+                .add_attribute("method", "execute_clear_before_send_hook")
+                .add_attribute("denom", denom)
+                .add_message(msg))
+        }
+
         // Merge these into a modify whitelist
         ExecuteMsg::AddWhitelist { addresses } => {
-            let config = CONFIG.load(deps.storage)?;
-            is_contract_manager(config.clone(), info.sender)?;
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender.clone())?;
+
+            let mut added: Vec<String> = vec![];
+            let mut pending: Vec<String> = vec![];
+            for new in validate_addresses(deps.api, "whitelist", &addresses)? {
+                let addr = Addr::unchecked(new);
+                // entries with an expiry become permanent
+                if WHITELIST.may_load(deps.storage, &addr)? != Some(WhitelistEntry::default()) {
+                    if stage_whitelist_entry(
+                        deps.storage,
+                        &config,
+                        &info.sender,
+                        &addr,
+                        WhitelistEntry::default(),
+                    )? {
+                        pending.push(addr.to_string());
+                    } else {
+                        added.push(addr.to_string());
+                    }
+                }
+            }
+
+            Ok(Response::new()
+                .add_attribute("method", "add_whitelist")
+                .add_attribute("added", added.join(","))
+                .add_attribute("pending", pending.join(",")))
+        }
+        ExecuteMsg::AddWhitelistWithExpiry { addresses, expires } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender.clone())?;
 
-            // add addresses if it is not in config.allowed_mint_addresses
-            let mut updated = config.allowed_mint_addresses;
-            for new in addresses {
-                if !updated.contains(&new) {
-                    updated.push(new);
+            if expires <= env.block.time {
+                return Err(ContractError::InvalidExpiry {});
+            }
+
+            // also replaces the expiry of addresses which are already whitelisted
+            let mut added: Vec<String> = vec![];
+            let mut pending: Vec<String> = vec![];
+            for addr in validate_addresses(deps.api, "whitelist", &addresses)? {
+                if stage_whitelist_entry(
+                    deps.storage,
+                    &config,
+                    &info.sender,
+                    &Addr::unchecked(&addr),
+                    WhitelistEntry {
+                        expires: Some(expires),
+                    },
+                )? {
+                    pending.push(addr);
+                } else {
+                    added.push(addr);
+                }
+            }
+
+            Ok(Response::new()
+                .add_attribute("method", "add_whitelist_with_expiry")
+                .add_attribute("added", added.join(","))
+                .add_attribute("pending", pending.join(","))
+                .add_attribute("expires", expires.to_string()))
+        }
+        ExecuteMsg::ConfirmWhitelist { addresses, denom } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender.clone())?;
+
+            let mut confirmed: Vec<String> = vec![];
+            for address in validate_addresses(deps.api, "whitelist", &addresses)? {
+                let addr = Addr::unchecked(&address);
+                let proposer = match &denom {
+                    Some(denom) => {
+                        PENDING_DENOM_MINTERS.may_load(deps.storage, (denom.as_str(), &addr))?
+                    }
+                    None => PENDING_WHITELIST
+                        .may_load(deps.storage, &addr)?
+                        .map(|pending| pending.proposer),
+                }
+                .ok_or_else(|| ContractError::NoPendingWhitelist {
+                    address: address.clone(),
+                })?;
+                // a sole manager can confirm its own additions
+                if config.managers.len() > 1 && proposer == info.sender.as_str() {
+                    return Err(ContractError::SameManagerConfirm { address });
+                }
+
+                match &denom {
+                    Some(denom) => {
+                        PENDING_DENOM_MINTERS.remove(deps.storage, (denom.as_str(), &addr));
+                        DENOM_MINTERS.save(deps.storage, (denom.as_str(), &addr), &Empty {})?;
+                    }
+                    None => {
+                        let pending = PENDING_WHITELIST.load(deps.storage, &addr)?;
+                        PENDING_WHITELIST.remove(deps.storage, &addr);
+                        save_whitelist_entry(deps.storage, &addr, &pending.entry)?;
+                    }
                 }
+                confirmed.push(address);
+            }
+
+            let mut res = Response::new()
+                .add_attribute("method", "confirm_whitelist")
+                .add_attribute("confirmed", confirmed.join(","));
+            if let Some(denom) = denom {
+                res = res.add_attribute("denom", denom);
             }
+            Ok(res)
+        }
+        ExecuteMsg::SetWhitelistApproval { enabled } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
 
+            // entries already pending stay confirmable after disabling
             CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-                config.allowed_mint_addresses = updated;
+                config.whitelist_approval = enabled;
                 Ok(config)
             })?;
 
-            Ok(Response::new().add_attribute("method", "add_whitelist"))
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_whitelist_approval")
+                .add_attribute("enabled", enabled.to_string()))
         }
-        ExecuteMsg::RemoveWhitelist { addresses } => {
-            let config = CONFIG.load(deps.storage)?;
-            is_contract_manager(config.clone(), info.sender)?;
 
-            let mut updated = config.allowed_mint_addresses;
-            for remove in addresses {
-                updated.retain(|a| a != &remove);
+        ExecuteMsg::SetLabel { label } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
+
+            if let Some(label) = &label {
+                validate_label(label)?;
             }
+            CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+                config.label = label;
+                Ok(config)
+            })?;
+
+            Ok(Response::new().add_attribute("method", "execute_set_label"))
+        }
+
+        ExecuteMsg::SetVoucherSigner { pubkey } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
 
+            // compressed or uncompressed, as accepted by secp256k1_verify
+            if let Some(pubkey) = &pubkey {
+                if pubkey.len() != 33 && pubkey.len() != 65 {
+                    return Err(ContractError::InvalidVoucherSigner {});
+                }
+            }
             CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-                config.allowed_mint_addresses = updated;
+                config.voucher_signer = pubkey;
                 Ok(config)
             })?;
-            Ok(Response::new().add_attribute("method", "remove_whitelist"))
+
+            Ok(Response::new().add_attribute("method", "execute_set_voucher_signer"))
         }
+        ExecuteMsg::RemoveWhitelist { addresses } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
 
-        ExecuteMsg::AddDenom { denoms } => {
-            let config = CONFIG.load(deps.storage)?;
+            let mut removed: Vec<String> = vec![];
+            for remove in validate_addresses(deps.api, "whitelist", &addresses)? {
+                let addr = Addr::unchecked(remove);
+                if WHITELIST.has(deps.storage, &addr) || PENDING_WHITELIST.has(deps.storage, &addr)
+                {
+                    remove_whitelist_entry(deps.storage, &addr)?;
+                    PENDING_WHITELIST.remove(deps.storage, &addr);
+                    removed.push(addr.to_string());
+                }
+            }
+
+            Ok(Response::new()
+                .add_attribute("method", "remove_whitelist")
+                .add_attribute("removed", removed.join(",")))
+        }
+        ExecuteMsg::SetWhitelist { addresses } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender.clone())?;
+
+            // validate everything before touching state
+            let addresses = validate_addresses(deps.api, "whitelist", &addresses)?;
+
+            let existing = WHITELIST
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<Addr>>>()?;
+
+            let mut removed: Vec<String> = vec![];
+            for addr in existing.iter() {
+                if !addresses.contains(&addr.to_string()) {
+                    remove_whitelist_entry(deps.storage, addr)?;
+                    removed.push(addr.to_string());
+                }
+            }
+            // proposals for addresses left out of the new list would otherwise still be confirmable
+            let stale = PENDING_WHITELIST
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<Addr>>>()?;
+            for addr in stale.iter() {
+                if !addresses.contains(&addr.to_string()) {
+                    PENDING_WHITELIST.remove(deps.storage, addr);
+                    if !existing.contains(addr) {
+                        removed.push(addr.to_string());
+                    }
+                }
+            }
+            let mut added: Vec<String> = vec![];
+            let mut pending: Vec<String> = vec![];
+            for new in addresses.iter() {
+                let addr = Addr::unchecked(new);
+                if !existing.contains(&addr) {
+                    if stage_whitelist_entry(
+                        deps.storage,
+                        &config,
+                        &info.sender,
+                        &addr,
+                        WhitelistEntry::default(),
+                    )? {
+                        pending.push(addr.to_string());
+                    } else {
+                        added.push(addr.to_string());
+                    }
+                }
+            }
+
+            Ok(Response::new()
+                .add_attribute("method", "set_whitelist")
+                .add_attribute("count", addresses.len().to_string())
+                .add_attribute("added", added.join(","))
+                .add_attribute("pending", pending.join(","))
+                .add_attribute("removed", removed.join(",")))
+        }
+
+        ExecuteMsg::AddBlocklist { addresses } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
+
+            for address in validate_addresses(deps.api, "blocklist", &addresses)? {
+                BLOCKLIST.save(deps.storage, &Addr::unchecked(address), &Empty {})?;
+            }
+
+            Ok(Response::new().add_attribute("method", "add_blocklist"))
+        }
+        ExecuteMsg::RemoveBlocklist { addresses } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
+
+            for address in validate_addresses(deps.api, "blocklist", &addresses)? {
+                BLOCKLIST.remove(deps.storage, &Addr::unchecked(address));
+            }
+
+            Ok(Response::new().add_attribute("method", "remove_blocklist"))
+        }
+
+        ExecuteMsg::SetDenomManager { denom, address } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+            is_managed_denom(&config, denom.as_str())?;
+
+            let address = match address {
+                Some(address) => {
+                    let address = validate_address(deps.api, "denom manager", &address)?;
+                    DENOM_MANAGERS.save(deps.storage, denom.as_str(), &address)?;
+                    address
+                }
+                None => {
+                    DENOM_MANAGERS.remove(deps.storage, denom.as_str());
+                    "".to_string()
+                }
+            };
+
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_denom_manager")
+                .add_attribute("denom", denom)
+                .add_attribute("denom_manager", address))
+        }
+
+        ExecuteMsg::AddDenomMinters { denom, addresses } => {
+            let config = load_config(deps.storage)?;
+            is_managed_denom(&config, denom.as_str())?;
+            is_denom_manager(
+                deps.storage,
+                config.clone(),
+                denom.as_str(),
+                info.sender.clone(),
+            )?;
+
+            // these can mint like whitelisted addresses, so they go through the same approval
+            let mut added: Vec<String> = vec![];
+            let mut pending: Vec<String> = vec![];
+            for minter in validate_addresses(deps.api, "minter", &addresses)? {
+                if stage_denom_minter(
+                    deps.storage,
+                    &config,
+                    &info.sender,
+                    denom.as_str(),
+                    &Addr::unchecked(&minter),
+                )? {
+                    pending.push(minter);
+                } else {
+                    added.push(minter);
+                }
+            }
+
+            Ok(Response::new()
+                .add_attribute("method", "add_denom_minters")
+                .add_attribute("denom", denom)
+                .add_attribute("added", added.join(","))
+                .add_attribute("pending", pending.join(",")))
+        }
+        ExecuteMsg::RemoveDenomMinters { denom, addresses } => {
+            let config = load_config(deps.storage)?;
+            is_denom_manager(deps.storage, config, denom.as_str(), info.sender)?;
+
+            for minter in validate_addresses(deps.api, "minter", &addresses)? {
+                let addr = Addr::unchecked(minter);
+                DENOM_MINTERS.remove(deps.storage, (denom.as_str(), &addr));
+                PENDING_DENOM_MINTERS.remove(deps.storage, (denom.as_str(), &addr));
+            }
+
+            Ok(Response::new()
+                .add_attribute("method", "remove_denom_minters")
+                .add_attribute("denom", denom))
+        }
+
+        ExecuteMsg::AddDenom { denoms, strict } => {
+            let config = load_config(deps.storage)?;
             is_contract_manager(config.clone(), info.sender)?;
 
+            let strict = strict.unwrap_or(false);
             let mut updated_denoms = config.denoms;
+            let mut added: Vec<String> = vec![];
             for new in denoms {
-                if !updated_denoms.contains(&new) {
-                    updated_denoms.push(new);
+                if updated_denoms.contains(&new) {
+                    if strict {
+                        return Err(ContractError::DenomAlreadyExists {
+                            denom: new.to_string(),
+                        });
+                    }
+                    continue;
                 }
+                added.push(new.to_string());
+                updated_denoms.push(new);
             }
 
             CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
@@ -238,101 +740,1236 @@ pub fn execute(
                 Ok(config)
             })?;
 
-            Ok(Response::new().add_attribute("method", "add_denom"))
+            Ok(Response::new()
+                .add_attribute("method", "add_denom")
+                .add_attribute("added", added.join(",")))
         }
-        ExecuteMsg::RemoveDenom { denoms } => {
-            let config = CONFIG.load(deps.storage)?;
+        ExecuteMsg::RemoveDenom { denoms, new_admin } => {
+            let config = load_config(deps.storage)?;
             is_contract_manager(config.clone(), info.sender)?;
 
+            let new_admin = match new_admin {
+                Some(new_admin) => Some(validate_address(deps.api, "new_admin", &new_admin)?),
+                None => None,
+            };
+
             let mut updated_denoms = config.denoms;
+            let mut removed: Vec<String> = vec![];
             for remove in denoms {
-                updated_denoms.retain(|a| a != &remove);
+                if updated_denoms.iter().any(|d| *d == remove) {
+                    ensure_no_collateral(deps.storage, remove.as_str())?;
+                    updated_denoms.retain(|d| *d != remove);
+                    removed.push(remove.to_string());
+                }
+            }
+
+            let mut res = Response::new()
+                .add_attribute("method", "remove_denom")
+                .add_attribute("removed", removed.join(","));
+
+            if let Some(new_admin) = new_admin {
+                for denom in removed.iter() {
+                    // same as TransferAdmin, the limits and grants are dropped with the admin
+                    clear_denom_state(deps.storage, denom)?;
+                    res = res.add_message(TokenFactoryMsg::ChangeAdmin {
+                        denom: denom.clone(),
+                        new_admin_address: new_admin.clone(),
+                    });
+                }
+                res = res.add_attribute("new_admin", new_admin);
+            }
+            // whitelisted addresses keep their rights, but every mint fails with InvalidDenom
+            if updated_denoms.is_empty() {
+                res = res.add_attribute(
+                    "warning",
+                    "No denoms are managed, minting fails until one is added",
+                );
             }
 
             CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
                 config.denoms = updated_denoms;
                 Ok(config)
             })?;
-            Ok(Response::new().add_attribute("method", "remove_denom"))
+            Ok(res)
         }
-    }
-}
 
-pub fn execute_transfer_admin(
-    deps: DepsMut,
-    info: MessageInfo,
-    denom: String,
-    new_addr: String,
-) -> Result<Response<TokenFactoryMsg>, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    is_contract_manager(config.clone(), info.sender)?;
+        ExecuteMsg::PurgeDenomState { denom } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
 
-    // it is possible to transfer admin in without adding to contract config. So devs need a way to reclaim admin without adding it to denoms config
-    let config_denom: Option<&String> = config.denoms.iter().find(|d| d.to_string() == denom);
+            if is_managed_denom(&config, denom.as_str()).is_ok() {
+                return Err(ContractError::DenomStillManaged {
+                    denom: denom.to_string(),
+                });
+            }
 
-    if let Some(config_denom) = config_denom {
-        // remove it from config
-        let updated_config: Vec<String> = config
-            .denoms
-            .iter()
-            .filter(|d| d.to_string() != *config_denom)
-            .map(|d| d.to_string())
-            .collect();
+            clear_denom_state(deps.storage, denom.as_str())?;
+            MINTED_TOTALS.remove(deps.storage, denom.as_str());
 
-        CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-            config.denoms = updated_config;
-            Ok(config)
-        })?;
-    }
+            Ok(Response::new()
+                .add_attribute("method", "purge_denom_state")
+                .add_attribute("denom", denom))
+        }
 
-    let msg = TokenFactoryMsg::ChangeAdmin {
-        denom: denom.to_string(),
-        new_admin_address: new_addr.to_string(),
-    };
+        ExecuteMsg::SetMaxSupply { denom, max_supply } => {
+            execute_set_max_supply(deps, info, denom, max_supply)
+        }
 
-    Ok(Response::new()
-        .add_attribute("method", "execute_transfer_admin")
-        .add_attribute("new_admin", new_addr)
-        .add_message(msg))
-}
+        ExecuteMsg::SetMaxMintPerTx { denom, amount } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+            is_managed_denom(&config, denom.as_str())?;
 
-pub fn execute_mint(
-    deps: DepsMut,
-    info: MessageInfo,
-    address: String,
-    denoms: Vec<Coin>,
-) -> Result<Response<TokenFactoryMsg>, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
+            match amount {
+                Some(amount) => {
+                    if amount.is_zero() {
+                        return Err(ContractError::ZeroAmount {});
+                    }
+                    MAX_MINT_PER_TX.save(deps.storage, denom.as_str(), &amount)?;
+                }
+                None => MAX_MINT_PER_TX.remove(deps.storage, denom.as_str()),
+            }
 
-    is_whitelisted(config, info.sender)?;
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_max_mint_per_tx")
+                .add_attribute("denom", denom)
+                .add_attribute("amount", amount.unwrap_or_default()))
+        }
 
-    let mint_msgs: Vec<TokenFactoryMsg> = mint_factory_token_messages(&address, &denoms)?;
+        ExecuteMsg::SetRateLimit { denom, limit } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+            is_managed_denom(&config, denom.as_str())?;
 
-    Ok(Response::new()
-        .add_attribute("method", "execute_mint")
-        .add_attribute("to_address", address)
-        .add_attribute("denoms", pretty_denoms_output(&denoms))
-        .add_messages(mint_msgs))
-}
+            match limit {
+                Some(limit) => {
+                    if limit.window_seconds == 0 {
+                        return Err(ContractError::InvalidRateLimit {
+                            message: "window_seconds must be greater than 0".to_string(),
+                        });
+                    }
+                    RATE_LIMITS.save(deps.storage, denom.as_str(), &limit)?;
+                }
+                None => RATE_LIMITS.remove(deps.storage, denom.as_str()),
+            }
+            // start the next mint in a fresh window
+            MINT_WINDOWS.remove(deps.storage, denom.as_str());
 
-pub fn execute_burn(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_rate_limit")
+                .add_attribute("denom", denom))
+        }
+
+        ExecuteMsg::SetTreasury { address } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+
+            let treasury = match address {
+                Some(address) => Some(validate_address(deps.api, "treasury", &address)?),
+                None => None,
+            };
+            // the mint fee has to go somewhere
+            if treasury.is_none() && config.mint_fee.is_some() {
+                return Err(ContractError::TreasuryRequired {});
+            }
+
+            CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+                config.treasury = treasury.clone();
+                Ok(config)
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_treasury")
+                .add_attribute("treasury", treasury.unwrap_or_default()))
+        }
+
+        ExecuteMsg::SetMintFee { fee } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+
+            if let Some(fee) = &fee {
+                if fee.amount.is_zero() {
+                    return Err(ContractError::ZeroAmount {});
+                }
+                if config.treasury.is_none() {
+                    return Err(ContractError::TreasuryRequired {});
+                }
+            }
+
+            CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+                config.mint_fee = fee.clone();
+                Ok(config)
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_mint_fee")
+                .add_attribute("fee", fee.map(|f| f.to_string()).unwrap_or_default()))
+        }
+
+        ExecuteMsg::SetVerifyDenomAdmin { enabled } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
+
+            CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+                config.verify_denom_admin = enabled;
+                Ok(config)
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_verify_denom_admin")
+                .add_attribute("enabled", enabled.to_string()))
+        }
+
+        ExecuteMsg::Sweep { denom, to_address } => {
+            execute_sweep(deps, env, info, denom, to_address)
+        }
+
+        ExecuteMsg::SetCollateralDenom { denom } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+
+            if let Some(denom) = &denom {
+                if denom.is_empty() {
+                    return Err(ContractError::InvalidDenom {
+                        denom: denom.clone(),
+                        message: "The collateral denom can not be empty".to_string(),
+                    });
+                }
+                // collateral in a denom this contract mints would back itself
+                if is_managed_denom(&config, denom).is_ok() {
+                    return Err(ContractError::InvalidDenom {
+                        denom: denom.clone(),
+                        message: "The collateral denom can not be managed by this contract"
+                            .to_string(),
+                    });
+                }
+            }
+
+            // deposits could otherwise never be released in the denom they were made in
+            if COLLATERAL
+                .keys(deps.storage, None, None, Order::Ascending)
+                .next()
+                .is_some()
+            {
+                return Err(ContractError::CollateralHeld {});
+            }
+
+            CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+                config.collateral_denom = denom.clone();
+                Ok(config)
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_collateral_denom")
+                .add_attribute("denom", denom.unwrap_or_default()))
+        }
+
+        ExecuteMsg::SetStrictBurn { enabled } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
+
+            CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+                config.strict_burn = enabled;
+                Ok(config)
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("method", "execute_set_strict_burn")
+                .add_attribute("enabled", enabled.to_string()))
+        }
+
+        ExecuteMsg::PruneMintIds { ids } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
+
+            for id in ids.iter() {
+                MINT_IDS.remove(deps.storage, id);
+            }
+
+            Ok(Response::new()
+                .add_attribute("method", "execute_prune_mint_ids")
+                .add_attribute("pruned", ids.len().to_string()))
+        }
+
+        ExecuteMsg::Pause {} => execute_set_paused(deps, info, true),
+        ExecuteMsg::Unpause {} => execute_set_paused(deps, info, false),
+
+        ExecuteMsg::DisableDenom { denom } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config.clone(), info.sender)?;
+            is_managed_denom(&config, denom.as_str())?;
+
+            DISABLED_DENOMS.save(deps.storage, denom.as_str(), &Empty {})?;
+            Ok(Response::new()
+                .add_attribute("method", "disable_denom")
+                .add_attribute("denom", denom))
+        }
+        ExecuteMsg::EnableDenom { denom } => {
+            let config = load_config(deps.storage)?;
+            is_contract_manager(config, info.sender)?;
+
+            DISABLED_DENOMS.remove(deps.storage, denom.as_str());
+            Ok(Response::new()
+                .add_attribute("method", "enable_denom")
+                .add_attribute("denom", denom))
+        }
+
+        ExecuteMsg::AddManager { address } => execute_add_manager(deps, info, address),
+        ExecuteMsg::RemoveManager { address } => execute_remove_manager(deps, info, address),
+
+        ExecuteMsg::ProposeNewManager { address } => {
+            execute_propose_new_manager(deps, env, info, address, None)
+        }
+
+        ExecuteMsg::SetManager {
+            new_manager,
+            confirm_no_recovery,
+        } => execute_set_manager(deps, info, new_manager, confirm_no_recovery),
+        ExecuteMsg::RenounceManager { confirm } => execute_renounce_manager(deps, info, confirm),
+
+        // == PENDING MANAGER ==
+        ExecuteMsg::AcceptManager {} => execute_accept_manager(deps, env, info),
+
+        ExecuteMsg::UpdateOwnership(action) => match action {
+            OwnershipAction::TransferOwnership { new_owner, expiry } => {
+                execute_propose_new_manager(deps, env, info, new_owner, expiry)
+            }
+            OwnershipAction::AcceptOwnership => execute_accept_manager(deps, env, info),
+            OwnershipAction::RenounceOwnership => execute_renounce_manager(deps, info, true),
+        },
+    }
+}
+
+pub fn execute_set_max_supply(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: Denom,
+    max_supply: Uint128,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+    is_managed_denom(&config, denom.as_str())?;
+
+    // a cap can only ever be lowered, otherwise holders could not trust it
+    if let Some(current) = MAX_SUPPLY.may_load(deps.storage, denom.as_str())? {
+        if max_supply > current {
+            return Err(ContractError::InvalidMaxSupply {
+                denom: denom.to_string(),
+                message: format!("Cannot raise the max supply above {current}"),
+            });
+        }
+    }
+
+    let supply = deps.querier.query_supply(denom.as_str())?;
+    if supply.amount > max_supply {
+        return Err(ContractError::InvalidMaxSupply {
+            denom: denom.to_string(),
+            message: format!("Current supply {} is above the max supply", supply.amount),
+        });
+    }
+
+    MAX_SUPPLY.save(deps.storage, denom.as_str(), &max_supply)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_set_max_supply")
+        .add_attribute("denom", denom)
+        .add_attribute("max_supply", max_supply))
+}
+
+pub fn execute_set_paused(
+    deps: DepsMut,
+    info: MessageInfo,
+    paused: bool,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config, info.sender)?;
+
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.paused = paused;
+        Ok(config)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_set_paused")
+        .add_attribute("paused", paused.to_string()))
+}
+
+pub fn execute_sweep(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    to_address: String,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+
+    if is_managed_denom(&config, &denom).is_ok() {
+        return Err(ContractError::CannotSweepManagedDenom { denom });
+    }
+    let to_address = validate_address(deps.api, "to_address", &to_address)?;
+
+    let mut amount = deps
+        .querier
+        .query_balance(&env.contract.address, &denom)?
+        .amount;
+    if config.collateral_denom.as_ref() == Some(&denom) {
+        let mut held = Uint128::zero();
+        for item in COLLATERAL.range(deps.storage, None, None, Order::Ascending) {
+            held = held.checked_add(item?.1)?;
+        }
+        amount = amount.saturating_sub(held);
+    }
+
+    let mut res = Response::new()
+        .add_attribute("method", "execute_sweep")
+        .add_attribute("denom", denom.clone())
+        .add_attribute("amount", amount)
+        .add_attribute("to_address", to_address.clone());
+    if !amount.is_zero() {
+        res = res.add_message(BankMsg::Send {
+            to_address,
+            amount: vec![Coin { denom, amount }],
+        });
+    }
+    Ok(res)
+}
+
+pub fn execute_add_manager(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config, info.sender)?;
+
+    let manager = validate_address(deps.api, "manager", &address)?;
+
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        if !config.managers.contains(&manager) {
+            config.managers.push(manager.clone());
+        }
+        Ok(config)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_add_manager")
+        .add_attribute("manager", manager))
+}
+
+pub fn execute_remove_manager(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let mut config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+
+    let manager = validate_address(deps.api, "manager", &address)?;
+
+    config.managers.retain(|m| *m != manager);
+    // someone must always be able to manage the contract (see RenounceManager)
+    if config.managers.is_empty() {
+        return Err(ContractError::LastManager {});
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    // a removed manager must not keep a way back in through its proposal
+    if let Some(pending) = PENDING_MANAGER.may_load(deps.storage)? {
+        if pending.proposer == manager {
+            PENDING_MANAGER.remove(deps.storage);
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_remove_manager")
+        .add_attribute("manager", manager))
+}
+
+pub fn execute_propose_new_manager(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    expiry: Option<Expiration>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config, info.sender.clone())?;
+
+    let pending = validate_address(deps.api, "manager", &address)?;
+
+    if expiry.is_some_and(|e| e.is_expired(&env.block)) {
+        return Err(ContractError::InvalidExpiry {});
+    }
+
+    // overwrites any previous proposal
+    PENDING_MANAGER.save(
+        deps.storage,
+        &PendingManager {
+            proposer: info.sender.to_string(),
+            address: pending.to_string(),
+            expiry,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_propose_new_manager")
+        .add_attribute("pending_manager", pending))
+}
+
+pub fn execute_set_manager(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_manager: String,
+    confirm_no_recovery: bool,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config, info.sender.clone())?;
+
+    if !confirm_no_recovery {
+        return Err(ContractError::ConfirmationRequired {});
+    }
+
+    let manager = validate_address(deps.api, "new_manager", &new_manager)?;
+
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.managers.retain(|m| *m != info.sender.as_str());
+        if !config.managers.contains(&manager) {
+            config.managers.push(manager.clone());
+        }
+        Ok(config)
+    })?;
+
+    // a proposal from the replaced manager can no longer be accepted
+    if let Some(pending) = PENDING_MANAGER.may_load(deps.storage)? {
+        if pending.proposer == info.sender.as_str() {
+            PENDING_MANAGER.remove(deps.storage);
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_set_manager")
+        .add_attribute("manager", manager)
+        .add_attribute("replaced", info.sender))
+}
+
+pub fn execute_renounce_manager(
+    deps: DepsMut,
+    info: MessageInfo,
+    confirm: bool,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config, info.sender)?;
+
+    if !confirm {
+        return Err(ContractError::ConfirmationRequired {});
+    }
+
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.renounced = true;
+        Ok(config)
+    })?;
+    PENDING_MANAGER.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("method", "execute_renounce_manager"))
+}
+
+pub fn execute_accept_manager(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let pending = PENDING_MANAGER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingManager {})?;
+
+    if info.sender.to_string() != pending.address {
+        return Err(ContractError::NotPendingManager {
+            sender: info.sender.to_string(),
+        });
+    }
+
+    if pending.expiry.is_some_and(|e| e.is_expired(&env.block)) {
+        return Err(ContractError::PendingManagerExpired {});
+    }
+
+    // the proposer hands over its seat, which it must still have
+    let mut config = load_config(deps.storage)?;
+    if !config.managers.contains(&pending.proposer) {
+        return Err(ContractError::ProposerNotManager {
+            proposer: pending.proposer,
+        });
+    }
+    config.managers.retain(|m| *m != pending.proposer);
+    if !config.managers.contains(&pending.address) {
+        config.managers.push(pending.address.clone());
+    }
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_MANAGER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_accept_manager")
+        .add_attribute("manager", pending.address)
+        .add_attribute("replaced", pending.proposer))
+}
+
+// Returns the full denom and the message creating it
+fn create_denom_submsg(
+    config: &Config,
+    env: &Env,
+    subdenom: String,
+) -> Result<(String, SubMsg<TokenFactoryMsg>), ContractError> {
+    validate_subdenom(&subdenom)?;
+
+    let full_denom = format!("factory/{}/{}", env.contract.address, subdenom);
+    if config.denoms.iter().any(|d| *d == full_denom) {
+        return Err(ContractError::InvalidDenom {
+            denom: full_denom,
+            message: "Denom is already managed by this contract".to_string(),
+        });
+    }
+
+    // the denom is only stored once the module confirms it was created, see `reply`
+    let msg = SubMsg::reply_on_success(
+        TokenFactoryMsg::CreateDenom {
+            subdenom,
+            metadata: None,
+        },
+        CREATE_DENOM_REPLY_ID,
+    );
+
+    Ok((full_denom, msg))
+}
+
+pub fn execute_create_denom(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    subdenom: String,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+
+    let (full_denom, msg) = create_denom_submsg(&config, &env, subdenom)?;
+
+    let data = CreateDenomResponse {
+        denom: full_denom.clone(),
+    };
+
+    Ok(Response::new()
+        .set_data(to_binary(&data)?)
+        .add_attribute("method", "execute_create_denom")
+        .add_attribute("denom", full_denom)
+        .add_submessage(msg))
+}
+
+/// Manages factory/<contract>/<subdenom> for every subdenom, creating the ones which do not exist yet.
+/// Denoms which already exist with this contract as the admin are only tracked, so it can be re-run safely.
+pub fn execute_ensure_denoms(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    subdenoms: Vec<String>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let mut config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+
+    if subdenoms.is_empty() {
+        return Err(ContractError::NoDenomsProvided {});
+    }
+    ensure_unique(&subdenoms)?;
+
+    let mut created: Vec<String> = vec![];
+    let mut added: Vec<String> = vec![];
+    let mut msgs: Vec<SubMsg<TokenFactoryMsg>> = vec![];
+    for subdenom in subdenoms {
+        validate_subdenom(&subdenom)?;
+        let full_denom = Denom::try_new(format!("factory/{}/{}", env.contract.address, subdenom))?;
+        if config.denoms.contains(&full_denom) {
+            continue;
+        }
+
+        // the module returns an empty admin for denoms which do not exist yet
+        let admin = query_denom_admin(deps.as_ref(), full_denom.as_str())?;
+        if admin.is_empty() {
+            let (full_denom, msg) = create_denom_submsg(&config, &env, subdenom)?;
+            created.push(full_denom);
+            msgs.push(msg);
+        } else if admin == env.contract.address.as_str() {
+            added.push(full_denom.to_string());
+            config.denoms.push(full_denom);
+        } else {
+            return Err(ContractError::NotDenomAdmin {
+                denom: full_denom.to_string(),
+            });
+        }
+    }
+
+    // created denoms are added by `reply`
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "ensure_denoms")
+        .add_attribute("created", created.join(","))
+        .add_attribute("added", added.join(","))
+        .add_submessages(msgs))
+}
+
+pub fn execute_create_and_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    subdenom: String,
+    initial_mint: Vec<(String, Uint128)>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+
+    let (full_denom, create_msg) = create_denom_submsg(&config, &env, subdenom)?;
+
+    let mut mint_msgs: Vec<TokenFactoryMsg> = vec![];
+    let mut minted = Uint128::zero();
+    for (address, amount) in initial_mint {
+        if amount.is_zero() {
+            return Err(ContractError::ZeroAmount {});
+        }
+        let address = validate_address(deps.api, "initial_mint", &address)?;
+        add_minted_total(deps.storage, &full_denom, amount)?;
+        minted = minted.checked_add(amount)?;
+        mint_msgs.push(mint_tokens_msg(address, full_denom.clone(), amount));
+    }
+    let display = mint_display(
+        deps.as_ref(),
+        &[Coin::new(minted.u128(), full_denom.clone())],
+    );
+
+    let data = CreateDenomResponse {
+        denom: full_denom.clone(),
+    };
+
+    // messages run in order, so the mints only happen once the denom exists
+    Ok(Response::new()
+        .set_data(to_binary(&data)?)
+        .add_attribute("method", "execute_create_and_mint")
+        .add_attribute("denom", full_denom)
+        .add_attribute("display", display)
+        .add_submessage(create_msg)
+        .add_messages(mint_msgs))
+}
+
+pub fn execute_burn_from(
+    deps: DepsMut,
+    info: MessageInfo,
+    from: String,
+    denom: Coin,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+    is_managed_denom(&config, &denom.denom)?;
+
+    if denom.amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+
+    let from = validate_address(deps.api, "from", &from)?;
+
+    // burn from from_address
+    let msg: TokenFactoryMsg = TokenFactoryMsg::BurnTokens {
+        denom: denom.denom.clone(),
+        amount: denom.amount,
+        burn_from_address: from.clone(),
+    };
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_burn_from")
+        .add_attribute("denom", denom.denom)
+        .add_attribute("amount", denom.amount)
+        .add_attribute("from", from)
+        .add_message(msg))
+}
+
+pub fn execute_transfer_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: Denom,
+    new_addr: String,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+
+    let new_addr = validate_address(deps.api, "new_address", &new_addr)?;
+
+    // it is possible to transfer admin in without adding to contract config. So devs need a way to reclaim admin without adding it to denoms config
+    if config.denoms.contains(&denom) {
+        // remove it from config
+        CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+            config.denoms.retain(|d| *d != denom);
+            Ok(config)
+        })?;
+    }
+
+    // limits and grants would otherwise come back to life if the admin is ever transferred back
+    clear_denom_state(deps.storage, denom.as_str())?;
+
+    let msg = TokenFactoryMsg::ChangeAdmin {
+        denom: denom.to_string(),
+        new_admin_address: new_addr.to_string(),
+    };
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_transfer_admin")
+        .add_attribute("new_admin", new_addr)
+        .add_message(msg))
+}
+
+pub fn execute_relinquish_all(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_admin: String,
+    pause: bool,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+
+    let new_admin = validate_address(deps.api, "new_admin", &new_admin)?;
+
+    let mut msgs: Vec<TokenFactoryMsg> = vec![];
+    for denom in config.denoms.iter() {
+        clear_denom_state(deps.storage, denom.as_str())?;
+        msgs.push(TokenFactoryMsg::ChangeAdmin {
+            denom: denom.to_string(),
+            new_admin_address: new_admin.clone(),
+        });
+    }
+
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.denoms = vec![];
+        if pause {
+            config.paused = true;
+        }
+        Ok(config)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_relinquish_all")
+        .add_attribute("new_admin", new_admin)
+        .add_attribute(
+            "relinquished",
+            config
+                .denoms
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        )
+        .add_attribute("pause", pause.to_string())
+        .add_messages(msgs))
+}
+
+pub fn execute_reclaim_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: Denom,
 ) -> Result<Response<TokenFactoryMsg>, ContractError> {
-    // Anyone can burn funds since they have to send them in.
-    if info.funds.is_empty() {
-        return Err(ContractError::InvalidFunds {});
+    let mut config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+
+    if config.denoms.contains(&denom) {
+        return Err(ContractError::DenomAlreadyExists {
+            denom: denom.to_string(),
+        });
+    }
+
+    let msg = TokenFactoryMsg::ChangeAdmin {
+        denom: denom.to_string(),
+        new_admin_address: env.contract.address.to_string(),
+    };
+
+    config.denoms.push(denom.clone());
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_reclaim_admin")
+        .add_attribute("denom", denom)
+        .add_message(msg))
+}
+
+pub fn execute_transfer_admin_batch(
+    deps: DepsMut,
+    info: MessageInfo,
+    transfers: Vec<(Denom, String)>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    is_contract_manager(config.clone(), info.sender)?;
+
+    if transfers.is_empty() || transfers.len() > MAX_BATCH_SIZE {
+        return Err(ContractError::InvalidBatchSize {
+            max: MAX_BATCH_SIZE,
+        });
+    }
+
+    // validate everything first so a bad entry fails the whole batch
+    let mut validated: Vec<(Denom, String)> = vec![];
+    for (denom, new_addr) in transfers {
+        let new_addr = validate_address(deps.api, "new_address", &new_addr)?;
+
+        match validated.iter().find(|(d, _)| *d == denom) {
+            // the same transfer listed twice is only sent once
+            Some((_, existing)) if *existing == new_addr => continue,
+            Some(_) => {
+                return Err(ContractError::InvalidDenom {
+                    denom: denom.to_string(),
+                    message: "Denom is listed with more than one new admin".to_string(),
+                })
+            }
+            None => validated.push((denom, new_addr)),
+        }
+    }
+
+    // as with TransferAdmin, denoms which are not in the config can still be transferred
+    let mut updated_denoms = config.denoms;
+    updated_denoms.retain(|d| !validated.iter().any(|(denom, _)| denom == d));
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.denoms = updated_denoms;
+        Ok(config)
+    })?;
+
+    let mut msgs: Vec<TokenFactoryMsg> = vec![];
+    for (denom, new_addr) in validated.iter() {
+        clear_denom_state(deps.storage, denom.as_str())?;
+        msgs.push(TokenFactoryMsg::ChangeAdmin {
+            denom: denom.to_string(),
+            new_admin_address: new_addr.clone(),
+        });
     }
 
-    let config = CONFIG.load(deps.storage)?;
+    Ok(Response::new()
+        .add_attribute("method", "execute_transfer_admin_batch")
+        .add_attribute("transfers", validated.len().to_string())
+        .add_messages(msgs))
+}
+
+/// Runs every mint check against the coins about to be minted (across all recipients)
+/// and records them against the denom rate limits.
+fn validate_mint(
+    deps: DepsMut,
+    env: &Env,
+    sender: Option<&Addr>,
+    coins: &[Coin],
+) -> Result<(), ContractError> {
+    for total in check_mint(deps.as_ref(), env, sender, coins)? {
+        consume_mint_allowance(deps.storage, &total.denom, total.amount, env.block.time)?;
+        add_minted_total(deps.storage, &total.denom, total.amount)?;
+    }
+    Ok(())
+}
+
+/// Runs every mint check without changing any state. Returns the total to mint of each denom.
+/// Without a sender, the mint was already authorized another way (a voucher) and only the limits apply.
+fn check_mint(
+    deps: Deps,
+    env: &Env,
+    sender: Option<&Addr>,
+    coins: &[Coin],
+) -> Result<Vec<Coin>, ContractError> {
+    let config = load_config(deps.storage)?;
+
+    // managers and globally whitelisted addresses can mint every denom, others need a per denom grant
+    let needs_grant = sender.filter(|sender| {
+        is_whitelisted(deps.storage, env, sender).is_err()
+            && is_contract_manager(config.clone(), (*sender).clone()).is_err()
+    });
+
+    // limits are checked against the total of each denom
+    let totals = aggregate_coins(coins)?;
+    for total in totals.iter() {
+        ensure_mintable(deps, env, &config, &total.denom)?;
+
+        if let Some(sender) = needs_grant {
+            if !DENOM_MINTERS.has(deps.storage, (total.denom.as_str(), sender)) {
+                return Err(ContractError::NotWhitelisted {
+                    sender: sender.to_string(),
+                });
+            }
+        }
+
+        check_max_mint_per_tx(deps.storage, &total.denom, total.amount)?;
+        check_max_supply(deps, &total.denom, total.amount)?;
+        check_mint_allowance(deps.storage, &total.denom, total.amount, env.block.time)?;
+    }
+
+    Ok(totals)
+}
+
+// Mirrors execute_mint, without the mint id and fee payment
+fn simulate_mint(
+    deps: Deps,
+    env: &Env,
+    sender: String,
+    address: String,
+    denoms: Vec<Coin>,
+) -> Result<SimulateMintResponse, ContractError> {
+    let sender = validate_address(deps.api, "sender", &sender)?;
+    let total = check_mint(deps, env, Some(&Addr::unchecked(sender)), &denoms)?;
+    mint_factory_token_messages(&address, &total)?;
 
-    let (factory_denoms, send_back): (Vec<Coin>, Vec<Coin>) = info
-        .funds
+    let config = load_config(deps.storage)?;
+    Ok(SimulateMintResponse {
+        address,
+        total,
+        fee: config.mint_fee,
+    })
+}
+
+// one event per coin so indexers do not have to parse the denoms attribute
+fn mint_events(address: &str, coins: &[Coin]) -> Vec<Event> {
+    coins
         .iter()
-        .cloned()
+        .map(|coin| {
+            Event::new("mint")
+                .add_attribute("denom", coin.denom.clone())
+                .add_attribute("amount", coin.amount)
+                .add_attribute("to_address", address)
+        })
+        .collect()
+}
+
+pub fn execute_mint(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    denoms: Vec<Coin>,
+    mint_id: Option<String>,
+    memo: Option<String>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    // one mint per denom, even if the caller listed a denom more than once
+    let denoms = aggregate_coins(&denoms)?;
+
+    if memo
+        .as_ref()
+        .is_some_and(|m| m.chars().count() > MAX_MEMO_LENGTH)
+    {
+        return Err(ContractError::MemoTooLong {
+            max: MAX_MEMO_LENGTH,
+        });
+    }
+
+    if let Some(mint_id) = &mint_id {
+        if mint_id.is_empty() || mint_id.len() > MAX_MINT_ID_LENGTH {
+            return Err(ContractError::InvalidMintId {
+                max: MAX_MINT_ID_LENGTH,
+            });
+        }
+        if MINT_IDS.has(deps.storage, mint_id) {
+            return Err(ContractError::DuplicateMintId {
+                mint_id: mint_id.clone(),
+            });
+        }
+        MINT_IDS.save(deps.storage, mint_id, &Empty {})?;
+    }
+
+    validate_mint(deps.branch(), &env, Some(&info.sender), &denoms)?;
+
+    let config = load_config(deps.storage)?;
+    let minted = sum_amounts(&denoms)?;
+    let fee_msgs = collect_mint_funds(&config, &info.funds, &info.sender, 1, minted)?;
+    add_collateral(deps.storage, &config, &denoms)?;
+
+    let mint_msgs: Vec<TokenFactoryMsg> = mint_factory_token_messages(&address, &denoms)?;
+
+    let data = MintResponse {
+        total: denoms.clone(),
+    };
+
+    let mut res = Response::new()
+        .set_data(to_binary(&data)?)
+        .add_attribute("method", "execute_mint")
+        .add_attribute("mint_id", mint_id.unwrap_or_default())
+        .add_attribute("to_address", address.clone())
+        .add_attribute("denoms", pretty_denoms_output(&denoms))
+        .add_attribute("display", mint_display(deps.as_ref(), &denoms));
+    if let Some(memo) = memo {
+        res = res.add_attribute("memo", memo);
+    }
+
+    Ok(res
+        .add_events(mint_events(&address, &denoms))
+        .add_messages(fee_msgs)
+        .add_messages(mint_msgs))
+}
+
+pub fn execute_mint_with_voucher(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    voucher: Voucher,
+    signature: Binary,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = load_config(deps.storage)?;
+    let signer = config
+        .voucher_signer
+        .clone()
+        .ok_or(ContractError::NoVoucherSigner {})?;
+
+    if env.block.time >= voucher.expiry {
+        return Err(ContractError::VoucherExpired {});
+    }
+    if voucher.nonce.is_empty() || voucher.nonce.len() > MAX_NONCE_LENGTH {
+        return Err(ContractError::InvalidNonce {
+            max: MAX_NONCE_LENGTH,
+        });
+    }
+    if USED_VOUCHERS.has(deps.storage, &voucher.nonce) {
+        return Err(ContractError::VoucherUsed {
+            nonce: voucher.nonce,
+        });
+    }
+
+    // the voucher is built from the message, so any field changed after signing fails here
+    let hash = Sha256::digest(to_vec(&voucher)?);
+    let valid = deps
+        .api
+        .secp256k1_verify(&hash, &signature, &signer)
+        .unwrap_or(false);
+    if !valid {
+        return Err(ContractError::InvalidVoucherSignature {});
+    }
+    USED_VOUCHERS.save(deps.storage, &voucher.nonce, &Empty {})?;
+
+    // signed as given, but minted to the normalized address
+    let recipient = validate_address(deps.api, "recipient", &voucher.recipient)?;
+    let denoms = vec![Coin {
+        denom: voucher.denom,
+        amount: voucher.amount,
+    }];
+    validate_mint(deps.branch(), &env, None, &denoms)?;
+
+    // the sender redeeming the voucher pays the fee and collateral, if any
+    let fee_msgs = collect_mint_funds(&config, &info.funds, &info.sender, 1, voucher.amount)?;
+    add_collateral(deps.storage, &config, &denoms)?;
+
+    let mint_msgs = mint_factory_token_messages(&recipient, &denoms)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_mint_with_voucher")
+        .add_attribute("nonce", voucher.nonce)
+        .add_attribute("to_address", recipient.clone())
+        .add_attribute("denoms", pretty_denoms_output(&denoms))
+        .add_attribute("display", mint_display(deps.as_ref(), &denoms))
+        .add_events(mint_events(&recipient, &denoms))
+        .add_messages(fee_msgs)
+        .add_messages(mint_msgs))
+}
+
+pub fn execute_mint_and_call(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+    denom: Coin,
+    msg: Binary,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let contract = ensure_contract_address(deps.as_ref(), "contract", &contract)?;
+    let config = load_config(deps.storage)?;
+    is_managed_denom(&config, &denom.denom)?;
+
+    // minted to this contract first, so the tokens can be sent along with the call
+    let address = env.contract.address.to_string();
+    let res = execute_mint(deps, env, info, address, vec![denom.clone()], None, None)?;
+
+    Ok(res
+        .add_attribute("call_contract", contract.clone())
+        .add_message(WasmMsg::Execute {
+            contract_addr: contract,
+            msg,
+            funds: vec![denom],
+        }))
+}
+
+pub fn execute_mint_batch(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mints: Vec<BatchMint>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    if mints.is_empty() || mints.len() > MAX_BATCH_SIZE {
+        return Err(ContractError::InvalidBatchSize {
+            max: MAX_BATCH_SIZE,
+        });
+    }
+
+    let all_coins: Vec<Coin> = mints.iter().flat_map(|m| m.denom.clone()).collect();
+    validate_mint(deps.branch(), &env, Some(&info.sender), &all_coins)?;
+
+    let config = load_config(deps.storage)?;
+    let minted = sum_amounts(&all_coins)?;
+    let fee_msgs = collect_mint_funds(
+        &config,
+        &info.funds,
+        &info.sender,
+        mints.len() as u128,
+        minted,
+    )?;
+    add_collateral(deps.storage, &config, &all_coins)?;
+
+    let mut mint_msgs: Vec<TokenFactoryMsg> = vec![];
+    let mut events: Vec<Event> = vec![];
+    for mint in mints.iter() {
+        let address = validate_address(deps.api, "mint", &mint.address)?;
+        let coins = aggregate_coins(&mint.denom)?;
+        mint_msgs.extend(mint_factory_token_messages(&address, &coins)?);
+        events.extend(mint_events(&address, &coins));
+    }
+
+    let total = aggregate_coins(&all_coins)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_mint_batch")
+        .add_attribute("recipients", mints.len().to_string())
+        .add_attribute("denoms", pretty_denoms_output(&total))
+        .add_attribute("display", mint_display(deps.as_ref(), &total))
+        .set_data(to_binary(&MintResponse { total })?)
+        .add_events(events)
+        .add_messages(fee_msgs)
+        .add_messages(mint_msgs))
+}
+
+// Splits funds sent to Burn into the managed denoms to burn and the rest to send back
+fn partition_burn_funds(
+    config: &Config,
+    funds: &[Coin],
+) -> Result<(Vec<Coin>, Vec<Coin>), ContractError> {
+    if funds.is_empty() {
+        return Err(ContractError::NoFunds {});
+    }
+
+    let (factory_denoms, send_back): (Vec<Coin>, Vec<Coin>) = aggregate_coins(funds)?
+        .into_iter()
         .partition(|coin| config.denoms.iter().any(|d| *d == coin.denom));
 
+    if config.strict_burn && !send_back.is_empty() {
+        return Err(ContractError::NonFactoryFunds {
+            denoms: pretty_denoms_output(&send_back),
+        });
+    }
+
+    Ok((factory_denoms, send_back))
+}
+
+/// The messages are always dispatched in the same order: one burn per managed denom (in the order the
+/// denoms were first sent), then the non factory funds being returned, then any released collateral.
+/// The transaction is atomic, so when any of them fails nothing is burned or returned.
+pub fn execute_burn(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    // Anyone can burn funds since they have to send them in.
+    let config = load_config(deps.storage)?;
+    let (factory_denoms, send_back) = partition_burn_funds(&config, &info.funds)?;
+
     let burn_msgs: Vec<TokenFactoryMsg> = factory_denoms
         .iter()
         .map(|coin| TokenFactoryMsg::BurnTokens {
@@ -342,24 +1979,292 @@ pub fn execute_burn(
         })
         .collect();
 
-    let bank_return_msg = BankMsg::Send {
-        to_address: info.sender.to_string(),
-        amount: send_back,
+    // non factory funds go to the treasury if there is one, otherwise back to the sender
+    let mut bank_msgs: Vec<BankMsg> = vec![];
+    if !send_back.is_empty() {
+        bank_msgs.push(BankMsg::Send {
+            to_address: config
+                .treasury
+                .clone()
+                .unwrap_or_else(|| info.sender.to_string()),
+            amount: send_back.clone(),
+        });
+    }
+
+    let released = release_collateral(deps.storage, &deps.querier, &config, &factory_denoms)?;
+    if let Some(released) = &released {
+        bank_msgs.push(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![released.clone()],
+        });
+    }
+
+    let burn_events: Vec<Event> = factory_denoms
+        .iter()
+        .map(|coin| {
+            Event::new("burn")
+                .add_attribute("denom", coin.denom.clone())
+                .add_attribute("amount", coin.amount)
+                .add_attribute("from_address", info.sender.to_string())
+        })
+        .collect();
+
+    let total_burned = pretty_denoms_output(&factory_denoms);
+    let total_returned = pretty_denoms_output(&send_back);
+
+    let data = BurnResponse {
+        burned: factory_denoms,
+        returned: send_back,
     };
 
     Ok(Response::new()
+        .set_data(to_binary(&data)?)
         .add_attribute("method", "execute_burn")
-        .add_message(bank_return_msg)
-        .add_messages(burn_msgs))
+        .add_attribute("total_burned", total_burned)
+        .add_attribute("total_returned", total_returned)
+        .add_attribute(
+            "collateral_released",
+            released.map(|c| c.to_string()).unwrap_or_default(),
+        )
+        .add_events(burn_events)
+        .add_messages(burn_msgs)
+        .add_messages(bank_msgs))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetConfig {} => {
             let config = CONFIG.load(deps.storage)?;
             to_binary(&config)
         }
+        QueryMsg::GetFullConfig {} => to_binary(&FullConfigResponse {
+            config: CONFIG.load(deps.storage)?,
+            contract_address: env.contract.address.to_string(),
+            version: get_contract_version(deps.storage)?,
+        }),
+        QueryMsg::GetDenoms {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&config.denoms)
+        }
+        QueryMsg::GetStats {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&StatsResponse {
+                num_whitelisted: WHITELIST_COUNT.may_load(deps.storage)?.unwrap_or_default(),
+                num_denoms: config.denoms.len() as u32,
+            })
+        }
+        QueryMsg::GetConfigFields { fields } => {
+            let config = CONFIG.load(deps.storage)?;
+            let mut res = ConfigFieldsResponse::default();
+            for field in fields {
+                match field {
+                    ConfigField::Managers => res.managers = Some(config.managers.clone()),
+                    ConfigField::Denoms => res.denoms = Some(config.denoms.clone()),
+                    ConfigField::Whitelist => {
+                        res.whitelist = Some(
+                            WHITELIST
+                                .keys(deps.storage, None, None, Order::Ascending)
+                                .map(|addr| addr.map(|a| a.to_string()))
+                                .collect::<StdResult<_>>()?,
+                        )
+                    }
+                }
+            }
+            to_binary(&res)
+        }
+        QueryMsg::GetConfigPage {
+            section,
+            start_after,
+            limit,
+        } => {
+            let config = CONFIG.load(deps.storage)?;
+            let page = match section {
+                ConfigField::Managers => list_page(config.managers, start_after, limit),
+                ConfigField::Denoms => list_page(
+                    config.denoms.into_iter().map(String::from).collect(),
+                    start_after,
+                    limit,
+                ),
+                ConfigField::Whitelist => whitelist_page(deps, start_after, limit)?,
+            };
+            to_binary(&page)
+        }
+        QueryMsg::GetDenomsByOrigin {} => {
+            let config = CONFIG.load(deps.storage)?;
+            let (self_created, external): (Vec<Denom>, Vec<Denom>) = config
+                .denoms
+                .into_iter()
+                .partition(|d| d.creator() == env.contract.address.as_str());
+            to_binary(&DenomsByOriginResponse {
+                self_created: self_created.into_iter().map(String::from).collect(),
+                external: external.into_iter().map(String::from).collect(),
+            })
+        }
+        QueryMsg::GetEnabledDenoms {} => {
+            let config = CONFIG.load(deps.storage)?;
+            let enabled: Vec<Denom> = config
+                .denoms
+                .into_iter()
+                .filter(|d| !DISABLED_DENOMS.has(deps.storage, d.as_str()))
+                .collect();
+            to_binary(&enabled)
+        }
+        QueryMsg::GetDenomStatuses { start_after, limit } => {
+            let config = CONFIG.load(deps.storage)?;
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let skip = match start_after {
+                Some(start) => config
+                    .denoms
+                    .iter()
+                    .position(|d| *d == start)
+                    .map_or(config.denoms.len(), |i| i + 1),
+                None => 0,
+            };
+
+            let statuses = config
+                .denoms
+                .iter()
+                .skip(skip)
+                .take(limit)
+                .map(|denom| {
+                    Ok(DenomStatus {
+                        denom: denom.to_string(),
+                        is_admin: query_denom_admin(deps, denom.as_str())?
+                            == env.contract.address.as_str(),
+                    })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            to_binary(&statuses)
+        }
+        QueryMsg::GetDenomPrefix {} => to_binary(&format!("factory/{}/", env.contract.address)),
+        QueryMsg::GetPendingManager {} => {
+            let pending = PENDING_MANAGER.may_load(deps.storage)?;
+            to_binary(&pending.map(|p| p.address))
+        }
+        QueryMsg::Ownership {} => {
+            let config = CONFIG.load(deps.storage)?;
+            let pending = PENDING_MANAGER.may_load(deps.storage)?;
+            let owner = match config.renounced {
+                true => None,
+                false => config.managers.first().cloned(),
+            };
+            to_binary(&Ownership {
+                owner,
+                pending_owner: pending.as_ref().map(|p| p.address.clone()),
+                pending_expiry: pending.and_then(|p| p.expiry),
+            })
+        }
+        QueryMsg::GetWhitelist { start_after, limit } => {
+            to_binary(&whitelist_page(deps, start_after, limit)?)
+        }
+        QueryMsg::IsWhitelisted { address } => {
+            // compare against the same canonical form stored by AddWhitelist
+            let address = deps.api.addr_validate(&address)?;
+            to_binary(&IsWhitelistedResponse {
+                whitelisted: is_whitelisted(deps.storage, &env, &address).is_ok(),
+            })
+        }
+        QueryMsg::IsManagedDenom { denom } => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&IsManagedDenomResponse {
+                managed: is_managed_denom(&config, &denom).is_ok(),
+            })
+        }
+        QueryMsg::IsPaused {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&IsPausedResponse {
+                paused: config.paused,
+            })
+        }
+        QueryMsg::IsDenomMintable { denom } => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&IsDenomMintableResponse {
+                mintable: ensure_mintable(deps, &env, &config, &denom).is_ok(),
+            })
+        }
+        QueryMsg::SimulateMint {
+            sender,
+            address,
+            denoms,
+        } => {
+            let res = simulate_mint(deps, &env, sender, address, denoms)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+            to_binary(&res)
+        }
+        QueryMsg::SimulateBurn { funds } => {
+            let config = CONFIG.load(deps.storage)?;
+            let (to_burn, to_return) = partition_burn_funds(&config, &funds)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+            to_binary(&SimulateBurnResponse { to_burn, to_return })
+        }
+        QueryMsg::GetDenomManager { denom } => {
+            let manager = DENOM_MANAGERS.may_load(deps.storage, &denom)?;
+            to_binary(&manager)
+        }
+        QueryMsg::GetMaxMintPerTx { denom } => {
+            let max = MAX_MINT_PER_TX.may_load(deps.storage, &denom)?;
+            to_binary(&max)
+        }
+        QueryMsg::GetMintFee {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&config.mint_fee)
+        }
+        QueryMsg::GetMintedTotal { denom } => {
+            let total = MINTED_TOTALS.may_load(deps.storage, &denom)?;
+            to_binary(&total.unwrap_or_default())
+        }
+        QueryMsg::GetMaxSupply { denom } => {
+            let max_supply = MAX_SUPPLY.may_load(deps.storage, &denom)?;
+            to_binary(&max_supply)
+        }
+        QueryMsg::GetDenomMetadata { denom } => {
+            let config = CONFIG.load(deps.storage)?;
+            is_managed_denom(&config, &denom).map_err(|e| StdError::generic_err(e.to_string()))?;
+            to_binary(&query_denom_metadata(deps, &denom))
+        }
+        QueryMsg::GetRateLimit { denom } => to_binary(&RATE_LIMITS.may_load(deps.storage, &denom)?),
+        QueryMsg::GetMintAllowance { denom } => {
+            let limit = RATE_LIMITS.may_load(deps.storage, &denom)?.ok_or_else(|| {
+                StdError::generic_err(format!("No rate limit is set for {denom:?}"))
+            })?;
+            let (remaining, resets_at) =
+                mint_allowance(deps.storage, &denom, &limit, env.block.time)?;
+            to_binary(&MintAllowanceResponse {
+                remaining,
+                resets_at,
+            })
+        }
+        QueryMsg::GetSupply { denom } => {
+            let config = CONFIG.load(deps.storage)?;
+            is_managed_denom(&config, &denom).map_err(|e| StdError::generic_err(e.to_string()))?;
+
+            // the bank module returns a zero amount for denoms with no supply
+            let supply = deps.querier.query_supply(denom)?;
+            to_binary(&supply)
+        }
+        QueryMsg::GetAllSupplies {} => {
+            let config = CONFIG.load(deps.storage)?;
+            let supplies = config
+                .denoms
+                .into_iter()
+                .map(|denom| deps.querier.query_supply(denom))
+                .collect::<StdResult<Vec<Coin>>>()?;
+            to_binary(&supplies)
+        }
+        QueryMsg::GetContractBalances {} => {
+            let config = CONFIG.load(deps.storage)?;
+            let mut balances: Vec<Coin> = vec![];
+            for denom in config.denoms {
+                let balance = deps
+                    .querier
+                    .query_balance(&env.contract.address, denom.as_str())?;
+                if !balance.amount.is_zero() {
+                    balances.push(balance);
+                }
+            }
+            to_binary(&balances)
+        }
         QueryMsg::GetBalance { address, denom } => {
             let v = BankQuery::Balance { address, denom };
             let res: BalanceResponse = deps.querier.query(&v.into())?;
@@ -376,3 +2281,191 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
     }
 }
+
+// Whitelisted addresses in ascending order, starting after `start_after`
+fn whitelist_page(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Addr::unchecked);
+
+    WHITELIST
+        .keys(
+            deps.storage,
+            start.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|addr| addr.map(|a| a.to_string()))
+        .collect()
+}
+
+// A page of a config list, in its stored order. An unknown `start_after` returns an empty page.
+fn list_page(list: Vec<String>, start_after: Option<String>, limit: Option<u32>) -> Vec<String> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let skip = match start_after {
+        Some(start) => list
+            .iter()
+            .position(|item| *item == start)
+            .map_or(list.len(), |i| i + 1),
+        None => 0,
+    };
+    list.into_iter().skip(skip).take(limit).collect()
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(
+    deps: DepsMut,
+    _env: Env,
+    msg: SudoMsg,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let res = match msg {
+        // transfers are allowed unless either side is blocked
+        SudoMsg::BlockBeforeSend { from, to, amount } => {
+            for address in [&from, &to] {
+                if BLOCKLIST.has(deps.storage, &Addr::unchecked(address)) {
+                    return Err(ContractError::Blocked {
+                        address: address.to_string(),
+                    });
+                }
+            }
+
+            Response::new()
+                .add_attribute("method", "sudo_block_before_send")
+                .add_attribute("denom", amount.denom)
+        }
+        SudoMsg::TrackBeforeSend { .. } => Response::new(),
+    };
+    add_label_attribute(deps.storage, res)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    mut deps: DepsMut,
+    _env: Env,
+    msg: Reply,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let res = match msg.id {
+        CREATE_DENOM_REPLY_ID => reply_create_denom(deps.branch(), msg)?,
+        id => return Err(ContractError::UnknownReplyId { id }),
+    };
+    add_label_attribute(deps.storage, res)
+}
+
+// Stores the denom reported by the tokenfactory module's create_denom event
+fn reply_create_denom(
+    deps: DepsMut,
+    msg: Reply,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let res = msg
+        .result
+        .into_result()
+        .map_err(|err| ContractError::InvalidReply { message: err })?;
+
+    let denom = res
+        .events
+        .iter()
+        .filter(|e| e.ty == "create_denom")
+        .flat_map(|e| e.attributes.iter())
+        .find(|a| a.key == "new_token_denom")
+        .map(|a| a.value.clone())
+        .ok_or_else(|| ContractError::InvalidReply {
+            message: "No new_token_denom in the create_denom event".to_string(),
+        })?;
+    let denom = Denom::try_new(denom)?;
+
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        if !config.denoms.contains(&denom) {
+            config.denoms.push(denom.clone());
+        }
+        Ok(config)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "reply_create_denom")
+        .add_attribute("denom", denom))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    mut deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let stored = get_contract_version(deps.storage)?;
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::InvalidMigration {
+            message: format!("Cannot migrate from contract {}", stored.contract),
+        });
+    }
+
+    // Only allow upgrades, never a downgrade or a re-run of the same version.
+    let stored_version: Version = stored.version.parse()?;
+    let new_version: Version = CONTRACT_VERSION.parse()?;
+    if stored_version >= new_version {
+        return Err(ContractError::InvalidMigration {
+            message: format!("Cannot migrate from {stored_version} to {new_version}"),
+        });
+    }
+
+    // State transforms between versions go here.
+    let mut skipped_denoms: Vec<String> = vec![];
+    if stored_version < Version::new(0, 2, 0) {
+        skipped_denoms = migrate_legacy_config(deps.branch())?;
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let res = Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("previous_version", stored_version.to_string())
+        .add_attribute("new_version", new_version.to_string())
+        .add_attribute("skipped_denoms", skipped_denoms.join(","));
+    add_label_attribute(deps.storage, res)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{attr, Storage};
+    use cw2::{get_contract_version, set_contract_version};
+
+    use super::{migrate, CONTRACT_NAME, CONTRACT_VERSION};
+    use crate::msg::MigrateMsg;
+    use crate::ContractError;
+
+    #[test]
+    fn migrate_bumps_the_version_and_never_downgrades() {
+        let mut deps = mock_dependencies();
+        // the config as stored by v0.0.4
+        deps.storage.set(
+            b"config",
+            br#"{"manager":"manager","allowed_mint_addresses":[],"denoms":[]}"#,
+        );
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.4").unwrap();
+
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert!(res.attributes.contains(&attr("previous_version", "0.0.4")));
+        assert_eq!(
+            get_contract_version(&deps.storage).unwrap().version,
+            CONTRACT_VERSION
+        );
+
+        // the same version again
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidMigration { .. }));
+
+        // a downgrade
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidMigration { .. }));
+
+        // another contract
+        set_contract_version(deps.as_mut().storage, "crates.io:other", "0.0.1").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidMigration { .. }));
+    }
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Coin, OverflowError, StdError, Timestamp, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,15 +6,192 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
-    #[error("Unauthorized")]
-    Unauthorized {},
+    // running totals (minted, rate limit windows, collateral) which no longer fit a Uint128
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
+    #[error("{sender} is not a contract manager")]
+    NotManager { sender: String },
+
+    #[error("{sender} is not a manager of {denom:?}")]
+    NotDenomManager { sender: String, denom: String },
+
+    #[error("{sender} is not whitelisted to mint")]
+    NotWhitelisted { sender: String },
+
+    #[error("{address} is blocked from transferring")]
+    Blocked { address: String },
+
+    #[error("Invalid {field} address: {address:?}")]
+    InvalidAddress { field: String, address: String },
+
+    #[error("Failed to {context}: {source}")]
+    Context { context: String, source: StdError },
+
+    #[error("The manager can not be empty, leave it unset to use the sender")]
+    EmptyManager {},
+
+    #[error("{value:?} is listed more than once")]
+    DuplicateEntry { value: String },
+
+    #[error("{denom} is already managed by this contract")]
+    DenomAlreadyExists { denom: String },
+
+    #[error("{denom} is still managed by this contract, remove it first")]
+    DenomStillManaged { denom: String },
+
+    #[error("{address} is not a contract")]
+    NotAContract { address: String },
 
     #[error("Invalid denom: {denom:?} {message:?}")]
     InvalidDenom { denom: String, message: String },
 
+    #[error("Invalid subdenom: {subdenom:?} {message:?}")]
+    InvalidSubdenom { subdenom: String, message: String },
+
     #[error("You did not specify any denoms.")]
     NoDenomsProvided {},
 
-    #[error("Invalid funds")]
-    InvalidFunds {},
+    #[error("No funds were sent")]
+    NoFunds {},
+
+    #[error("Unexpected funds of {denom:?} were sent")]
+    UnexpectedDenom { denom: String },
+
+    #[error("Insufficient funds, {needed} is required but {got} was sent")]
+    InsufficientAmount { needed: Coin, got: Coin },
+
+    #[error("Only managed denoms can be burned, got {denoms}")]
+    NonFactoryFunds { denoms: String },
+
+    #[error("Amount must be greater than zero")]
+    ZeroAmount {},
+
+    #[error("Invalid coin {amount}{denom:?}: {message}")]
+    InvalidCoin {
+        denom: String,
+        amount: Uint128,
+        message: String,
+    },
+
+    #[error("Minting would exceed the max supply of {max_supply} for {denom:?}")]
+    MaxSupplyExceeded { denom: String, max_supply: Uint128 },
+
+    #[error("Cannot mint more than {max} of {denom:?} at once")]
+    MintAmountTooLarge { denom: String, max: Uint128 },
+
+    #[error("Invalid max supply for {denom:?}: {message:?}")]
+    InvalidMaxSupply { denom: String, message: String },
+
+    #[error("Rate limit exceeded for {denom:?}: {remaining} remaining until {resets_at}")]
+    RateLimitExceeded {
+        denom: String,
+        remaining: Uint128,
+        resets_at: Timestamp,
+    },
+
+    #[error("Invalid rate limit: {message:?}")]
+    InvalidRateLimit { message: String },
+
+    #[error("Batch must contain between 1 and {max} entries")]
+    InvalidBatchSize { max: usize },
+
+    #[error("This contract is not the admin of {denom:?}")]
+    NotDenomAdmin { denom: String },
+
+    #[error("Managed denom {denom:?} can not be swept, burn it instead")]
+    CannotSweepManagedDenom { denom: String },
+
+    #[error("The collateral denom can not be changed while collateral is held")]
+    CollateralHeld {},
+
+    #[error("Collateral is still held for {denom:?}, burn its supply first")]
+    DenomCollateralHeld { denom: String },
+
+    #[error("A treasury must be set to collect the mint fee")]
+    TreasuryRequired {},
+
+    #[error("Mint id {mint_id:?} has already been used")]
+    DuplicateMintId { mint_id: String },
+
+    #[error("Mint ids must be between 1 and {max} characters")]
+    InvalidMintId { max: usize },
+
+    #[error("Memos can be at most {max} characters")]
+    MemoTooLong { max: usize },
+
+    #[error("Labels must be between 1 and {max} characters")]
+    InvalidLabel { max: usize },
+
+    #[error("No voucher signer is set")]
+    NoVoucherSigner {},
+
+    #[error("The voucher signer must be a 33 or 65 byte secp256k1 public key")]
+    InvalidVoucherSigner {},
+
+    #[error("The voucher is not signed by the voucher signer")]
+    InvalidVoucherSignature {},
+
+    #[error("The voucher expired")]
+    VoucherExpired {},
+
+    #[error("Voucher {nonce:?} was already redeemed")]
+    VoucherUsed { nonce: String },
+
+    #[error("Voucher nonces must be between 1 and {max} bytes")]
+    InvalidNonce { max: usize },
+
+    #[error("Minting is paused")]
+    MintingPaused {},
+
+    #[error("Minting {denom:?} is disabled")]
+    DenomDisabled { denom: String },
+
+    #[error("Management of this contract has been renounced")]
+    ManagementRenounced {},
+
+    #[error("This action is irreversible and must be confirmed")]
+    ConfirmationRequired {},
+
+    #[error("The manager transfer has expired")]
+    PendingManagerExpired {},
+
+    #[error("The expiry has already passed")]
+    InvalidExpiry {},
+
+    #[error("{address} is not pending whitelist confirmation")]
+    NoPendingWhitelist { address: String },
+
+    #[error("{address} must be confirmed by a different manager than the one who added it")]
+    SameManagerConfirm { address: String },
+
+    #[error("There is no pending manager transfer")]
+    NoPendingManager {},
+
+    #[error("{sender} is not the pending manager")]
+    NotPendingManager { sender: String },
+
+    #[error("{proposer} is no longer a manager, so its transfer can not be accepted")]
+    ProposerNotManager { proposer: String },
+
+    #[error("Cannot remove the last contract manager")]
+    LastManager {},
+
+    #[error("Invalid migration: {message:?}")]
+    InvalidMigration { message: String },
+
+    #[error("Unknown reply id: {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("Invalid reply: {message:?}")]
+    InvalidReply { message: String },
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
 }
@@ -1,29 +1,667 @@
-use cosmwasm_std::{Addr, Coin, Uint128};
-use token_bindings::{DenomUnit, Metadata, TokenFactoryMsg};
+use cosmwasm_std::{
+    Addr, Api, BankMsg, Binary, Coin, ContractInfoResponse, CosmosMsg, DenomMetadata, Deps, Empty,
+    Env, Order, QuerierWrapper, QueryRequest, Response, StdResult, Storage, Timestamp, Uint128,
+    WasmQuery,
+};
+use juno_tokenfactory_types::msg::RateLimit;
+use token_bindings::{AdminResponse, DenomUnit, Metadata, TokenFactoryMsg, TokenFactoryQuery};
 
-use crate::{msg::NewDenom, state::Config, ContractError};
+use crate::{
+    msg::NewDenom,
+    state::{
+        Config, MintWindow, PendingWhitelistEntry, WhitelistEntry, COLLATERAL, CONFIG,
+        DENOM_MANAGERS, DENOM_MINTERS, DISABLED_DENOMS, MAX_MINT_PER_TX, MAX_SUPPLY, MINTED_TOTALS,
+        MINT_WINDOWS, PENDING_DENOM_MINTERS, PENDING_WHITELIST, RATE_LIMITS, WHITELIST,
+        WHITELIST_COUNT,
+    },
+    ContractError,
+};
 
 pub use juno_tokenfactory_types::msg::ExecuteMsg::Mint;
 
-pub fn is_whitelisted(state: Config, sender: Addr) -> Result<(), ContractError> {
-    if !state.allowed_mint_addresses.contains(&sender.to_string()) {
-        return Err(ContractError::Unauthorized {});
+// The tokenfactory module rejects subdenoms longer than this
+pub const MAX_SUBDENOM_LENGTH: usize = 44;
+
+// Keeps the mint id storage keys small
+pub const MAX_MINT_ID_LENGTH: usize = 64;
+
+// Same for the redeemed voucher nonces
+pub const MAX_NONCE_LENGTH: usize = 64;
+
+// Mint memos are only emitted as an attribute, this keeps them from bloating the tx
+pub const MAX_MEMO_LENGTH: usize = 256;
+
+// Labels are emitted on every response, so they are kept short
+pub const MAX_LABEL_LENGTH: usize = 64;
+
+// Keeps the batch messages (MintBatch, TransferAdminBatch) within a reasonable gas limit
+pub const MAX_BATCH_SIZE: usize = 50;
+
+/// Fails for addresses which were never whitelisted, or whose entry expired
+pub fn is_whitelisted(
+    storage: &dyn Storage,
+    env: &Env,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    let active = WHITELIST
+        .may_load(storage, sender)?
+        .is_some_and(|entry| entry.expires.is_none_or(|expires| env.block.time < expires));
+    if !active {
+        return Err(ContractError::NotWhitelisted {
+            sender: sender.to_string(),
+        });
     }
     Ok(())
 }
 
 pub fn is_contract_manager(config: Config, sender: Addr) -> Result<(), ContractError> {
-    if !config.manager.eq(&sender.to_string()) {
-        return Err(ContractError::Unauthorized {});
+    if config.renounced {
+        return Err(ContractError::ManagementRenounced {});
+    }
+    if !config.managers.contains(&sender.to_string()) {
+        return Err(ContractError::NotManager {
+            sender: sender.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Labels must be non empty and at most MAX_LABEL_LENGTH characters
+pub fn validate_label(label: &str) -> Result<(), ContractError> {
+    if label.is_empty() || label.len() > MAX_LABEL_LENGTH {
+        return Err(ContractError::InvalidLabel {
+            max: MAX_LABEL_LENGTH,
+        });
+    }
+    Ok(())
+}
+
+/// Adds the contract_label attribute to a response, if a label is set
+pub fn add_label_attribute(
+    storage: &dyn Storage,
+    res: Response<TokenFactoryMsg>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    Ok(match load_config(storage)?.label {
+        Some(label) => res.add_attribute("contract_label", label),
+        None => res,
+    })
+}
+
+/// Whitelists the address, or stages it for another manager to confirm when whitelist approval
+/// is enabled. Returns true if the entry was staged.
+pub fn stage_whitelist_entry(
+    storage: &mut dyn Storage,
+    config: &Config,
+    proposer: &Addr,
+    address: &Addr,
+    entry: WhitelistEntry,
+) -> StdResult<bool> {
+    if config.whitelist_approval {
+        PENDING_WHITELIST.save(
+            storage,
+            address,
+            &PendingWhitelistEntry {
+                proposer: proposer.to_string(),
+                entry,
+            },
+        )?;
+        return Ok(true);
+    }
+    save_whitelist_entry(storage, address, &entry)?;
+    Ok(false)
+}
+
+/// Adds or replaces the whitelist entry, keeping WHITELIST_COUNT in sync
+pub fn save_whitelist_entry(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    entry: &WhitelistEntry,
+) -> StdResult<()> {
+    if !WHITELIST.has(storage, address) {
+        let count = WHITELIST_COUNT.may_load(storage)?.unwrap_or_default();
+        WHITELIST_COUNT.save(storage, &(count + 1))?;
+    }
+    WHITELIST.save(storage, address, entry)
+}
+
+/// Removes the whitelist entry if there is one, keeping WHITELIST_COUNT in sync
+pub fn remove_whitelist_entry(storage: &mut dyn Storage, address: &Addr) -> StdResult<()> {
+    if WHITELIST.has(storage, address) {
+        let count = WHITELIST_COUNT.may_load(storage)?.unwrap_or_default();
+        WHITELIST_COUNT.save(storage, &count.saturating_sub(1))?;
+        WHITELIST.remove(storage, address);
+    }
+    Ok(())
+}
+
+/// Same as stage_whitelist_entry, for a minter of a single denom
+pub fn stage_denom_minter(
+    storage: &mut dyn Storage,
+    config: &Config,
+    proposer: &Addr,
+    denom: &str,
+    address: &Addr,
+) -> StdResult<bool> {
+    if config.whitelist_approval {
+        PENDING_DENOM_MINTERS.save(storage, (denom, address), &proposer.to_string())?;
+        return Ok(true);
+    }
+    DENOM_MINTERS.save(storage, (denom, address), &Empty {})?;
+    Ok(false)
+}
+
+/// Passes for the contract managers and the manager delegated for this denom
+pub fn is_denom_manager(
+    storage: &dyn Storage,
+    config: Config,
+    denom: &str,
+    sender: Addr,
+) -> Result<(), ContractError> {
+    if config.renounced {
+        return Err(ContractError::ManagementRenounced {});
+    }
+    if is_contract_manager(config, sender.clone()).is_ok() {
+        return Ok(());
+    }
+    if DENOM_MANAGERS.may_load(storage, denom)? != Some(sender.to_string()) {
+        return Err(ContractError::NotDenomManager {
+            sender: sender.to_string(),
+            denom: denom.to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn is_managed_denom(config: &Config, denom: &str) -> Result<(), ContractError> {
+    if !config.denoms.iter().any(|d| d == denom) {
+        return Err(ContractError::InvalidDenom {
+            denom: denom.to_string(),
+            message: "Denom is not managed by this contract".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Ensures `amount` is within the denom's per transaction limit (if one is set)
+pub fn check_max_mint_per_tx(
+    storage: &dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if let Some(max) = MAX_MINT_PER_TX.may_load(storage, denom)? {
+        if amount > max {
+            return Err(ContractError::MintAmountTooLarge {
+                denom: denom.to_string(),
+                max,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Ensures minting `amount` more of a denom does not go over its max supply (if one is set)
+pub fn check_max_supply(deps: Deps, denom: &str, amount: Uint128) -> Result<(), ContractError> {
+    if let Some(max_supply) = MAX_SUPPLY.may_load(deps.storage, denom)? {
+        let supply = deps.querier.query_supply(denom)?;
+        if supply.amount.checked_add(amount)? > max_supply {
+            return Err(ContractError::MaxSupplyExceeded {
+                denom: denom.to_string(),
+                max_supply,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Charges the mint fee (if one is set) for `mints` mints out of the funds sent along,
+/// and takes `minted` of the collateral denom (if one is set), which stays in this contract.
+/// The fee is sent to the treasury and anything paid on top of both is refunded to the sender.
+/// When neither is set, no funds may be sent at all.
+pub fn collect_mint_funds(
+    config: &Config,
+    funds: &[Coin],
+    sender: &Addr,
+    mints: u128,
+    minted: Uint128,
+) -> Result<Vec<BankMsg>, ContractError> {
+    let fee = match &config.mint_fee {
+        Some(fee) => Some(Coin {
+            denom: fee.denom.clone(),
+            amount: fee.amount.checked_mul(Uint128::from(mints))?,
+        }),
+        None => None,
+    };
+    let collateral = config.collateral_denom.as_ref().map(|denom| Coin {
+        denom: denom.clone(),
+        amount: minted,
+    });
+    if fee.is_none() && collateral.is_none() {
+        return match funds.first() {
+            Some(coin) => Err(ContractError::UnexpectedDenom {
+                denom: coin.denom.clone(),
+            }),
+            None => Ok(vec![]),
+        };
+    }
+    if funds.is_empty() {
+        return Err(ContractError::NoFunds {});
+    }
+
+    // the fee and collateral may be the same denom, so the funds must cover both together
+    let required: Vec<Coin> = fee.iter().chain(collateral.iter()).cloned().collect();
+    for needed in aggregate_coins(&required)? {
+        let got = funds
+            .iter()
+            .filter(|c| c.denom == needed.denom)
+            .try_fold(Uint128::zero(), |sum, c| sum.checked_add(c.amount))?;
+        if got < needed.amount {
+            return Err(ContractError::InsufficientAmount {
+                got: Coin {
+                    denom: needed.denom.clone(),
+                    amount: got,
+                },
+                needed,
+            });
+        }
+    }
+
+    let mut remaining = aggregate_coins(funds)?;
+    let mut msgs: Vec<BankMsg> = vec![];
+
+    if let Some(fee) = fee {
+        let treasury = config
+            .treasury
+            .clone()
+            .ok_or(ContractError::TreasuryRequired {})?;
+        take_coin(&mut remaining, &fee);
+        msgs.push(BankMsg::Send {
+            to_address: treasury,
+            amount: vec![fee],
+        });
+    }
+
+    if let Some(collateral) = collateral {
+        take_coin(&mut remaining, &collateral);
+    }
+
+    let refund: Vec<Coin> = remaining
+        .into_iter()
+        .filter(|c| !c.amount.is_zero())
+        .collect();
+    if !refund.is_empty() {
+        msgs.push(BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: refund,
+        });
+    }
+    Ok(msgs)
+}
+
+/// Records the collateral deposited for each minted denom, 1:1 with the amount minted
+pub fn add_collateral(
+    storage: &mut dyn Storage,
+    config: &Config,
+    minted: &[Coin],
+) -> Result<(), ContractError> {
+    if config.collateral_denom.is_none() {
+        return Ok(());
+    }
+    for coin in minted {
+        COLLATERAL.update(storage, &coin.denom, |held| -> Result<_, ContractError> {
+            Ok(held.unwrap_or_default().checked_add(coin.amount)?)
+        })?;
+    }
+    Ok(())
+}
+
+/// Releases the collateral of burned denoms, proportional to the share of each denom's supply burned:
+/// `held * burned / supply`. This rounds down, so the dust stays held for the remaining supply
+/// until the last of it is burned, which releases everything left.
+pub fn release_collateral(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper,
+    config: &Config,
+    burned: &[Coin],
+) -> Result<Option<Coin>, ContractError> {
+    let collateral_denom = match &config.collateral_denom {
+        Some(denom) => denom,
+        None => return Ok(None),
+    };
+
+    let mut released = Uint128::zero();
+    for coin in burned {
+        let held = COLLATERAL
+            .may_load(storage, &coin.denom)?
+            .unwrap_or_default();
+        if held.is_zero() {
+            continue;
+        }
+
+        // the burn message has not run yet, so this is the supply before the burn
+        let supply = querier.query_supply(&coin.denom)?.amount;
+        if supply.is_zero() {
+            continue;
+        }
+        // the supply includes the burned coins, so this is at most everything held
+        let release = held.multiply_ratio(coin.amount, supply).min(held);
+        if release == held {
+            COLLATERAL.remove(storage, &coin.denom);
+        } else {
+            COLLATERAL.save(storage, &coin.denom, &(held - release))?;
+        }
+        released = released.checked_add(release)?;
+    }
+
+    if released.is_zero() {
+        return Ok(None);
+    }
+    Ok(Some(Coin {
+        denom: collateral_denom.clone(),
+        amount: released,
+    }))
+}
+
+// Subtracts `coin` from the funds, which have already been checked to cover it
+fn take_coin(funds: &mut [Coin], coin: &Coin) {
+    if let Some(c) = funds.iter_mut().find(|c| c.denom == coin.denom) {
+        c.amount = c.amount.saturating_sub(coin.amount);
+    }
+}
+
+/// How much of a rate limited denom can still be minted at `now`, and when that resets.
+/// An expired window counts as a fresh one, so its full limit is remaining.
+pub fn mint_allowance(
+    storage: &dyn Storage,
+    denom: &str,
+    limit: &RateLimit,
+    now: Timestamp,
+) -> StdResult<(Uint128, Timestamp)> {
+    let window = current_mint_window(storage, denom, limit, now)?;
+    Ok((
+        limit.amount.saturating_sub(window.minted),
+        window.started_at.plus_seconds(limit.window_seconds),
+    ))
+}
+
+/// Returns the mint window of a rate limited denom at `now`. Expired windows are treated as a fresh one.
+pub fn current_mint_window(
+    storage: &dyn Storage,
+    denom: &str,
+    limit: &RateLimit,
+    now: Timestamp,
+) -> StdResult<MintWindow> {
+    let window = match MINT_WINDOWS.may_load(storage, denom)? {
+        Some(w) if now < w.started_at.plus_seconds(limit.window_seconds) => w,
+        _ => MintWindow {
+            started_at: now,
+            minted: Uint128::zero(),
+        },
+    };
+    Ok(window)
+}
+
+/// Returns the denom's mint window with `amount` added, erroring if it is over the allowance.
+/// None if the denom is not rate limited.
+pub fn check_mint_allowance(
+    storage: &dyn Storage,
+    denom: &str,
+    amount: Uint128,
+    now: Timestamp,
+) -> Result<Option<MintWindow>, ContractError> {
+    let limit = match RATE_LIMITS.may_load(storage, denom)? {
+        Some(limit) => limit,
+        None => return Ok(None),
+    };
+
+    let mut window = current_mint_window(storage, denom, &limit, now)?;
+    let minted = window.minted.checked_add(amount)?;
+    if minted > limit.amount {
+        return Err(ContractError::RateLimitExceeded {
+            denom: denom.to_string(),
+            remaining: limit.amount.saturating_sub(window.minted),
+            resets_at: window.started_at.plus_seconds(limit.window_seconds),
+        });
+    }
+
+    window.minted = minted;
+    Ok(Some(window))
+}
+
+/// Records `amount` against the denom's rate limit (if one is set), erroring if it is over the allowance
+pub fn consume_mint_allowance(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+    now: Timestamp,
+) -> Result<(), ContractError> {
+    if let Some(window) = check_mint_allowance(storage, denom, amount, now)? {
+        MINT_WINDOWS.save(storage, denom, &window)?;
+    }
+    Ok(())
+}
+
+/// Returns the current tokenfactory admin of the denom
+pub fn query_denom_admin(deps: Deps, denom: &str) -> StdResult<String> {
+    // the entry points use the default querier, but the module query is a custom one
+    let querier = QuerierWrapper::<TokenFactoryQuery>::new(&*deps.querier);
+    let res: AdminResponse = querier.query(&QueryRequest::Custom(TokenFactoryQuery::Admin {
+        denom: denom.to_string(),
+    }))?;
+    Ok(res.admin)
+}
+
+/// The bank metadata of a denom. The bank module errors when none is set, which is returned as None.
+pub fn query_denom_metadata(deps: Deps, denom: &str) -> Option<DenomMetadata> {
+    deps.querier.query_denom_metadata(denom).ok()
+}
+
+/// Ensures `admin` is the current tokenfactory admin of the denom
+pub fn check_denom_admin(deps: Deps, denom: &str, admin: &Addr) -> Result<(), ContractError> {
+    if query_denom_admin(deps, denom)? != admin.as_str() {
+        return Err(ContractError::NotDenomAdmin {
+            denom: denom.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Every gate a denom must pass before any mint of it, so all mint entry points apply the same ones:
+/// the global pause, being managed by this contract, not being disabled and, when
+/// `verify_denom_admin` is set, this contract still being the denom admin.
+pub fn ensure_mintable(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    denom: &str,
+) -> Result<(), ContractError> {
+    if config.paused {
+        return Err(ContractError::MintingPaused {});
+    }
+
+    is_managed_denom(config, denom)?;
+
+    if DISABLED_DENOMS.has(deps.storage, denom) {
+        return Err(ContractError::DenomDisabled {
+            denom: denom.to_string(),
+        });
+    }
+
+    if config.verify_denom_admin {
+        check_denom_admin(deps, denom, &env.contract.address)?;
+    }
+    Ok(())
+}
+
+/// Adds to the total ever minted of a denom, see MINTED_TOTALS
+pub fn add_minted_total(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    MINTED_TOTALS.update(storage, denom, |total| -> Result<_, ContractError> {
+        Ok(total.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(())
+}
+
+/// Errors if collateral is held for the denom. Burns only release it while the contract manages
+/// the denom, so it would otherwise be stuck once the denom is removed.
+pub fn ensure_no_collateral(storage: &dyn Storage, denom: &str) -> Result<(), ContractError> {
+    if COLLATERAL.has(storage, denom) {
+        return Err(ContractError::DenomCollateralHeld {
+            denom: denom.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Removes all state kept for a single denom, once the contract no longer controls it:
+/// max supply, max mint per tx, rate limit, current mint window, the disabled flag, the denom
+/// manager and minters, pending or not. Fails while collateral is held for it, see ensure_no_collateral.
+pub fn clear_denom_state(storage: &mut dyn Storage, denom: &str) -> Result<(), ContractError> {
+    ensure_no_collateral(storage, denom)?;
+
+    MAX_SUPPLY.remove(storage, denom);
+    MAX_MINT_PER_TX.remove(storage, denom);
+    DENOM_MANAGERS.remove(storage, denom);
+    RATE_LIMITS.remove(storage, denom);
+    MINT_WINDOWS.remove(storage, denom);
+    DISABLED_DENOMS.remove(storage, denom);
+
+    let minters = DENOM_MINTERS
+        .prefix(denom)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for minter in minters {
+        DENOM_MINTERS.remove(storage, (denom, &minter));
+    }
+    let pending = PENDING_DENOM_MINTERS
+        .prefix(denom)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for minter in pending {
+        PENDING_DENOM_MINTERS.remove(storage, (denom, &minter));
+    }
+    Ok(())
+}
+
+/// Ensures a subdenom is accepted by the tokenfactory module, so a bad one fails before the message is sent.
+/// '/' is not allowed as managed denoms must have exactly 3 segments.
+pub fn validate_subdenom(subdenom: &str) -> Result<(), ContractError> {
+    if subdenom.is_empty() || subdenom.len() > MAX_SUBDENOM_LENGTH {
+        return Err(ContractError::InvalidSubdenom {
+            subdenom: subdenom.to_string(),
+            message: format!("Subdenom must be between 1 and {MAX_SUBDENOM_LENGTH} characters"),
+        });
+    }
+
+    if let Some(c) = subdenom
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')))
+    {
+        return Err(ContractError::InvalidSubdenom {
+            subdenom: subdenom.to_string(),
+            message: format!("Subdenom contains the illegal character {c:?}"),
+        });
+    }
+
+    Ok(())
+}
+
+/// Loads the config, saying so in the error if it fails
+pub fn load_config(storage: &dyn Storage) -> Result<Config, ContractError> {
+    CONFIG
+        .load(storage)
+        .map_err(|source| ContractError::Context {
+            context: "load the config".to_string(),
+            source,
+        })
+}
+
+/// Validates an address and returns it in its canonical (normalized) form.
+/// `field` names what the address is for in the error, e.g. "new_admin".
+pub fn validate_address(
+    api: &dyn Api,
+    field: &str,
+    address: &str,
+) -> Result<String, ContractError> {
+    api.addr_validate(address)
+        .map(|addr| addr.to_string())
+        .map_err(|_| ContractError::InvalidAddress {
+            field: field.to_string(),
+            address: address.to_string(),
+        })
+}
+
+/// Ensures the address belongs to a contract, not a regular account
+pub fn ensure_contract_address(
+    deps: Deps,
+    field: &str,
+    address: &str,
+) -> Result<String, ContractError> {
+    let address = validate_address(deps.api, field, address)?;
+    let info: StdResult<ContractInfoResponse> = deps.querier.query(
+        &WasmQuery::ContractInfo {
+            contract_addr: address.clone(),
+        }
+        .into(),
+    );
+    if info.is_err() {
+        return Err(ContractError::NotAContract { address });
+    }
+    Ok(address)
+}
+
+/// Validates every address and returns them in their canonical (normalized) form.
+/// Duplicates are only kept once, in the order they were first seen.
+pub fn validate_addresses(
+    api: &dyn Api,
+    field: &str,
+    addresses: &[String],
+) -> Result<Vec<String>, ContractError> {
+    let mut validated: Vec<String> = vec![];
+    for address in addresses {
+        let addr = validate_address(api, field, address)?;
+        if !validated.contains(&addr) {
+            validated.push(addr);
+        }
+    }
+    Ok(validated)
+}
+
+/// Errors on the first value which is listed more than once
+pub fn ensure_unique<T: PartialEq + ToString>(values: &[T]) -> Result<(), ContractError> {
+    for (i, value) in values.iter().enumerate() {
+        if values[..i].contains(value) {
+            return Err(ContractError::DuplicateEntry {
+                value: value.to_string(),
+            });
+        }
     }
     Ok(())
 }
 
-/// Creates the token messages to mint factory tokens to an address (from this middleware contract)
-/// If there are no denoms provided to mint (standard coins), it will return an error
+/// Creates the token messages to mint factory tokens to an address.
+/// If there are no denoms provided to mint (standard coins) it will return an error,
+/// as will any coin with an empty denom or a zero amount. The error names the offending coin.
+///
+/// The messages only succeed when sent by the denom admin, so other contracts should only use this
+/// for denoms they hold the admin of. Everyone else should send a Mint to this contract instead.
+///
+/// ```
+/// use cosmwasm_std::{coin, coins};
+/// use juno_tokenfactory_core::mint_factory_token_messages;
+///
+/// let address = "juno1xxx".to_string();
+/// let msgs = mint_factory_token_messages(&address, &coins(100, "factory/juno1yyy/test")).unwrap();
+/// assert_eq!(msgs.len(), 1);
+///
+/// assert!(mint_factory_token_messages(&address, &vec![]).is_err());
 ///
-/// You should not use this function unless you are within this contract. It is not for other contract use
-/// unless you also use TokenFactoryMsg's, which is the entire point of this contract to not have to do.
+/// let mixed = vec![coin(1, "factory/juno1yyy/a"), coin(0, "factory/juno1yyy/b")];
+/// let err = mint_factory_token_messages(&address, &mixed).unwrap_err();
+/// assert!(err.to_string().contains("factory/juno1yyy/b"));
+/// ```
 pub fn mint_factory_token_messages(
     address: &String,
     denoms: &Vec<Coin>,
@@ -32,9 +670,23 @@ pub fn mint_factory_token_messages(
         return Err(ContractError::NoDenomsProvided {});
     }
 
+    for d in denoms {
+        let message = if d.denom.is_empty() {
+            "denom can not be empty"
+        } else if d.amount.is_zero() {
+            "amount must be greater than zero"
+        } else {
+            continue;
+        };
+        return Err(ContractError::InvalidCoin {
+            denom: d.denom.clone(),
+            amount: d.amount,
+            message: message.to_string(),
+        });
+    }
+
     let msgs: Vec<TokenFactoryMsg> = denoms
         .iter()
-        .filter(|d| denoms.iter().any(|d2| d2.denom == d.denom))
         .map(|d| TokenFactoryMsg::MintTokens {
             denom: d.denom.clone(),
             amount: d.amount,
@@ -45,8 +697,35 @@ pub fn mint_factory_token_messages(
     Ok(msgs)
 }
 
-// Makes the output of a vector of denominations much pretty. In the format:
-// 1000000:factory/juno1xxx/test, 1000000:factory/juno1xxx/test2
+/// Sums the amounts of coins sharing a denom, keeping the order each denom was first seen in.
+pub fn aggregate_coins(coins: &[Coin]) -> Result<Vec<Coin>, ContractError> {
+    let mut totals: Vec<Coin> = vec![];
+    for coin in coins {
+        match totals.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(total) => total.amount = total.amount.checked_add(coin.amount)?,
+            None => totals.push(coin.clone()),
+        }
+    }
+    Ok(totals)
+}
+
+/// Sums the amounts of all coins, regardless of their denom
+pub fn sum_amounts(coins: &[Coin]) -> Result<Uint128, ContractError> {
+    Ok(coins
+        .iter()
+        .try_fold(Uint128::zero(), |sum, c| sum.checked_add(c.amount))?)
+}
+
+/// Makes the output of a vector of denominations much pretty. In the format:
+/// 1000000:factory/juno1xxx/test, 1000000:factory/juno1xxx/test2
+///
+/// ```
+/// use cosmwasm_std::{coin, Coin};
+/// use juno_tokenfactory_core::pretty_denoms_output;
+///
+/// let denoms: Vec<Coin> = vec![coin(5, "factory/juno1xxx/a"), coin(7, "factory/juno1xxx/b")];
+/// assert_eq!(pretty_denoms_output(&denoms), "5:factory/juno1xxx/a, 7:factory/juno1xxx/b");
+/// ```
 pub fn pretty_denoms_output(denoms: &[Coin]) -> String {
     denoms
         .iter()
@@ -55,6 +734,91 @@ pub fn pretty_denoms_output(denoms: &[Coin]) -> String {
         .join(", ")
 }
 
+/// Like pretty_denoms_output, but denoms with a known display unit (see display_unit) are shown in it:
+/// 1.5 TEST, 7:factory/juno1xxx/test2
+///
+/// ```
+/// use cosmwasm_std::{coin, Coin};
+/// use juno_tokenfactory_core::helpers::pretty_denoms_display;
+///
+/// let denoms: Vec<Coin> = vec![coin(1_500_000, "factory/juno1xxx/a"), coin(7, "factory/juno1xxx/b")];
+/// let units = |denom: &str| (denom == "factory/juno1xxx/a").then(|| ("A".to_string(), 6));
+/// assert_eq!(pretty_denoms_display(&denoms, units), "1.5 A, 7:factory/juno1xxx/b");
+///
+/// // without any known units it is the same as pretty_denoms_output
+/// assert_eq!(pretty_denoms_display(&denoms, |_| None), "1500000:factory/juno1xxx/a, 7:factory/juno1xxx/b");
+/// ```
+pub fn pretty_denoms_display(
+    denoms: &[Coin],
+    mut units: impl FnMut(&str) -> Option<(String, u32)>,
+) -> String {
+    denoms
+        .iter()
+        .map(|d| match units(&d.denom) {
+            Some((label, exponent)) => format!("{} {}", display_amount(d.amount, exponent), label),
+            None => format!("{}:{}", d.amount, d.denom),
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Formats a base amount in a unit `exponent` decimals larger, without trailing zeros
+///
+/// ```
+/// use cosmwasm_std::Uint128;
+/// use juno_tokenfactory_core::helpers::display_amount;
+///
+/// assert_eq!(display_amount(Uint128::new(1_000_000), 6), "1");
+/// assert_eq!(display_amount(Uint128::new(1_234_500), 6), "1.2345");
+/// assert_eq!(display_amount(Uint128::new(5), 6), "0.000005");
+/// assert_eq!(display_amount(Uint128::new(5), 0), "5");
+/// ```
+pub fn display_amount(amount: Uint128, exponent: u32) -> String {
+    // 10^39 no longer fits a u128, so larger exponents are left in base units
+    let unit = match 10u128.checked_pow(exponent) {
+        Some(unit) if exponent > 0 => unit,
+        _ => return amount.to_string(),
+    };
+    let whole = amount.u128() / unit;
+    let fraction = amount.u128() % unit;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let fraction = format!("{:0width$}", fraction, width = exponent as usize);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+/// The label (symbol, or else the display denom) and exponent of a denom's display unit.
+/// None when the metadata does not list its display unit.
+pub fn display_unit(metadata: &DenomMetadata) -> Option<(String, u32)> {
+    let unit = metadata
+        .denom_units
+        .iter()
+        .find(|u| u.denom == metadata.display)?;
+    let label = if metadata.symbol.is_empty() {
+        metadata.display.clone()
+    } else {
+        metadata.symbol.clone()
+    };
+    Some((label, unit.exponent))
+}
+
+/// The `display` attribute of every mint: the minted coins in display units where the bank
+/// metadata is known. The metadata of each denom is only queried once per call.
+pub fn mint_display(deps: Deps, coins: &[Coin]) -> String {
+    let mut units: Vec<(String, Option<(String, u32)>)> = vec![];
+    pretty_denoms_display(coins, |denom| {
+        if let Some((_, unit)) = units.iter().find(|(d, _)| d == denom) {
+            return unit.clone();
+        }
+        let unit = query_denom_metadata(deps, denom)
+            .as_ref()
+            .and_then(display_unit);
+        units.push((denom.to_string(), unit.clone()));
+        unit
+    })
+}
+
 pub fn create_denom_msg(subdenom: String, full_denom: String, denom: NewDenom) -> TokenFactoryMsg {
     TokenFactoryMsg::CreateDenom {
         subdenom,
@@ -87,3 +851,40 @@ pub fn mint_tokens_msg(address: String, denom: String, amount: Uint128) -> Token
         mint_to_address: address,
     }
 }
+
+/// The token bindings have no before send hook message, so it is sent as a stargate message.
+/// An empty `cosmwasm_address` removes the hook.
+pub fn set_before_send_hook_msg(
+    sender: String,
+    denom: String,
+    cosmwasm_address: String,
+) -> CosmosMsg<TokenFactoryMsg> {
+    CosmosMsg::Stargate {
+        type_url: "/osmosis.tokenfactory.v1beta1.MsgSetBeforeSendHook".to_string(),
+        value: Binary::from(encode_proto_strings(&[&sender, &denom, &cosmwasm_address])),
+    }
+}
+
+// Protobuf encodes a message made up of only string fields, numbered from 1 in order
+fn encode_proto_strings(fields: &[&str]) -> Vec<u8> {
+    let mut buf: Vec<u8> = vec![];
+    for (i, value) in fields.iter().enumerate() {
+        // proto3 does not encode default (empty) values
+        if value.is_empty() {
+            continue;
+        }
+        // field number with the length-delimited wire type
+        encode_varint((((i + 1) << 3) | 2) as u64, &mut buf);
+        encode_varint(value.len() as u64, &mut buf);
+        buf.extend_from_slice(value.as_bytes());
+    }
+    buf
+}
+
+fn encode_varint(mut value: u64, buf: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
@@ -0,0 +1,63 @@
+use cosmwasm_std::{to_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::msg::ExecuteMsg;
+
+/// Helper for contracts which call a deployed core contract, building its messages in a
+/// type-safe way. Depend on this crate with the `library` feature to leave out the entry points.
+///
+/// ```
+/// use cosmwasm_std::{coins, Addr, CosmosMsg, WasmMsg};
+/// use juno_tokenfactory_core::CoreContract;
+///
+/// let core = CoreContract(Addr::unchecked("juno1core"));
+/// let msg = core.mint("juno1user", coins(5, "factory/juno1core/test")).unwrap();
+///
+/// match msg {
+///     CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, funds, .. }) => {
+///         assert_eq!(contract_addr, "juno1core");
+///         assert!(funds.is_empty());
+///     }
+///     _ => panic!("expected a wasm execute"),
+/// }
+///
+/// // managed denoms sent along are burned
+/// let burn = core.burn(coins(5, "factory/juno1core/test")).unwrap();
+/// assert!(matches!(burn, CosmosMsg::Wasm(WasmMsg::Execute { .. })));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoreContract(pub Addr);
+
+impl CoreContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    /// Executes any message on the core contract with `funds` attached
+    pub fn call_with_funds(&self, msg: ExecuteMsg, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_binary(&msg)?,
+            funds,
+        }
+        .into())
+    }
+
+    pub fn call(&self, msg: ExecuteMsg) -> StdResult<CosmosMsg> {
+        self.call_with_funds(msg, vec![])
+    }
+
+    /// Mints `coins` to `address`, the calling contract must be whitelisted
+    pub fn mint(&self, address: impl Into<String>, coins: Vec<Coin>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::Mint {
+            address: address.into(),
+            denom: coins,
+            mint_id: None,
+            memo: None,
+        })
+    }
+
+    /// Burns the managed denoms in `funds`, the rest is returned (or sent to the treasury)
+    pub fn burn(&self, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        self.call_with_funds(ExecuteMsg::Burn {}, funds)
+    }
+}
@@ -1,7 +1,13 @@
 pub mod contract;
 mod error;
 pub mod helpers;
+mod interface;
+mod migrations;
 pub mod msg;
 pub mod state;
 
 pub use crate::error::ContractError;
+pub use crate::helpers::{mint_factory_token_messages, pretty_denoms_output};
+pub use crate::interface::CoreContract;
+pub use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+pub use crate::state::Config;
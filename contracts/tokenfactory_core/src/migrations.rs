@@ -0,0 +1,105 @@
+use cosmwasm_std::{DepsMut, StdResult};
+use cw_storage_plus::Item;
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::save_whitelist_entry;
+use crate::msg::Denom;
+use crate::state::{Config, WhitelistEntry, CONFIG};
+
+// Config layout up to v0.0.4, where the whitelist was stored inline
+#[derive(Serialize, Deserialize)]
+struct LegacyConfig {
+    manager: String,
+    allowed_mint_addresses: Vec<String>,
+    denoms: Vec<String>,
+}
+
+/// v0.2.0: moves the whitelist out of the config and into its own map, and the single manager
+/// becomes the first entry of the managers list. Returns the denoms which did not validate,
+/// these are dropped as every mint of them would fail.
+pub fn migrate_legacy_config(deps: DepsMut) -> StdResult<Vec<String>> {
+    let legacy: Item<LegacyConfig> = Item::new("config");
+    let old = legacy.load(deps.storage)?;
+
+    for address in old.allowed_mint_addresses {
+        // entries which never validated could never mint, so they are dropped
+        if let Ok(addr) = deps.api.addr_validate(&address) {
+            save_whitelist_entry(deps.storage, &addr, &WhitelistEntry::default())?;
+        }
+    }
+
+    let mut denoms: Vec<Denom> = vec![];
+    let mut skipped: Vec<String> = vec![];
+    for denom in old.denoms {
+        match Denom::try_new(denom.clone()) {
+            Ok(denom) => denoms.push(denom),
+            Err(_) => skipped.push(denom),
+        }
+    }
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            managers: vec![old.manager],
+            denoms,
+            paused: false,
+            treasury: None,
+            renounced: false,
+            verify_denom_admin: false,
+            mint_fee: None,
+            strict_burn: false,
+            collateral_denom: None,
+            whitelist_approval: false,
+            label: None,
+            voucher_signer: None,
+        },
+    )?;
+    Ok(skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{attr, Addr, Order, StdResult, Storage};
+    use cw2::set_contract_version;
+
+    use crate::contract::migrate;
+    use crate::msg::{Denom, MigrateMsg};
+    use crate::state::{CONFIG, WHITELIST, WHITELIST_COUNT};
+
+    #[test]
+    fn migrates_the_v0_0_4_config() {
+        let mut deps = mock_dependencies();
+        // exactly as v0.0.4 stored it, with an address which never validated and a native denom
+        deps.storage.set(
+            b"config",
+            br#"{"manager":"manager","allowed_mint_addresses":["minter","NotNormalized"],"denoms":["factory/creator/abc","ujuno"]}"#,
+        );
+        set_contract_version(
+            deps.as_mut().storage,
+            "crates.io:tokenfactory-core",
+            "0.0.4",
+        )
+        .unwrap();
+
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert!(res.attributes.contains(&attr("skipped_denoms", "ujuno")));
+
+        let config = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(config.managers, vec!["manager".to_string()]);
+        assert_eq!(
+            config.denoms,
+            vec![Denom::try_new("factory/creator/abc").unwrap()]
+        );
+        assert!(!config.paused);
+        assert!(!config.renounced);
+        assert!(!config.whitelist_approval);
+
+        let whitelist = WHITELIST
+            .keys(&deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>()
+            .unwrap();
+        assert_eq!(whitelist, vec![Addr::unchecked("minter")]);
+        assert_eq!(WHITELIST_COUNT.load(&deps.storage).unwrap(), 1);
+    }
+}
@@ -7,10 +7,43 @@ pub struct InstantiateMsg {
     // Default is the contract initializer
     pub manager: Option<String>,
     pub allowed_mint_addresses: Vec<String>,
+    // (denom, minters) which may only mint that denom, like AddDenomMinters. Each denom must be one below.
+    pub denom_minters: Option<Vec<(String, Vec<String>)>>,
 
-    // We can manage multiple denoms
-    pub existing_denoms: Option<Vec<String>>, // ex: factory/juno1xxx/test
+    // We can manage multiple denoms, or none to start with
+    pub existing_denoms: Option<Vec<Denom>>, // ex: factory/juno1xxx/test
     pub new_denoms: Option<Vec<NewDenom>>,
+    // (denom, metadata) set right after the denoms are created, each denom must be one of the above.
+    // Existing denoms only accept it if their admin is already this contract (e.g. with instantiate2).
+    pub denom_metadata: Option<Vec<(String, Metadata)>>,
+
+    // Non factory funds sent to burn are forwarded here instead of back to the sender
+    pub treasury: Option<String>,
+    // check the contract is the denom admin before every mint (default false)
+    pub verify_denom_admin: Option<bool>,
+    // reject non factory funds sent to burn instead of returning them (default false)
+    pub strict_burn: Option<bool>,
+    // emitted as the contract_label attribute of every response, e.g. to group instances in an indexer
+    pub label: Option<String>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+// Called by the tokenfactory module for denoms with this contract as the before send hook
+#[cw_serde]
+pub enum SudoMsg {
+    BlockBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+    // sent alongside BlockBeforeSend, but can not stop the transfer
+    TrackBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
 }
 
 #[cw_serde]
@@ -28,8 +61,12 @@ pub struct InitialBalance {
     pub amount: Uint128,
 }
 
-use cosmwasm_std::{Coin, Uint128};
-pub use juno_tokenfactory_types::msg::ExecuteMsg;
+use cosmwasm_std::{Coin, DenomMetadata, Timestamp, Uint128};
+use cw2::ContractVersion;
+use cw_utils::Expiration;
+pub use juno_tokenfactory_types::denom::Denom;
+pub use juno_tokenfactory_types::msg::{BatchMint, ExecuteMsg, OwnershipAction, RateLimit};
+use token_bindings::Metadata;
 
 #[cw_serde]
 #[derive(QueryResponses)]
@@ -37,9 +74,260 @@ pub enum QueryMsg {
     #[returns(crate::state::Config)]
     GetConfig {},
 
+    // The config along with this contract's address and cw2 version
+    #[returns(FullConfigResponse)]
+    GetFullConfig {},
+
+    // Only the requested parts of the config, the others are None
+    #[returns(ConfigFieldsResponse)]
+    GetConfigFields { fields: Vec<ConfigField> },
+
+    // A page of a single config list, for lists too large for GetConfig. Managers and denoms are in the
+    // order they were added, the whitelist in ascending order.
+    #[returns(Vec<String>)]
+    GetConfigPage {
+        section: ConfigField,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    #[returns(Vec<Denom>)]
+    GetDenoms {},
+
+    // Managed denoms split by whether this contract created them or they were transferred in
+    #[returns(DenomsByOriginResponse)]
+    GetDenomsByOrigin {},
+
+    // Managed denoms which are not disabled, so can be minted
+    #[returns(Vec<Denom>)]
+    GetEnabledDenoms {},
+
+    // Sizes of the whitelist and denom list, for monitoring
+    #[returns(StatsResponse)]
+    GetStats {},
+
+    // Whether this contract is still the admin of each managed denom, in the order they were added.
+    // Queries the module once per denom, so it is paginated.
+    #[returns(Vec<DenomStatus>)]
+    GetDenomStatuses {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // factory/<contract>/, which CreateDenom prepends to the subdenom
+    #[returns(String)]
+    GetDenomPrefix {},
+
+    // The address which has been proposed as a manager, if a transfer is in progress
+    #[returns(Option<String>)]
+    GetPendingManager {},
+
+    // cw-ownable compatible view of the managers, where the owner is the first manager
+    #[returns(Ownership)]
+    Ownership {},
+
+    // Whitelisted addresses in ascending order, starting after `start_after`
+    #[returns(Vec<String>)]
+    GetWhitelist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    #[returns(IsWhitelistedResponse)]
+    IsWhitelisted { address: String },
+
+    // Funds of managed denoms sent to Burn are burned, all others are returned
+    #[returns(IsManagedDenomResponse)]
+    IsManagedDenom { denom: String },
+
+    #[returns(IsPausedResponse)]
+    IsPaused {},
+
+    // Whether a mint of the denom would pass the pause, managed and disabled checks (see ensure_mintable)
+    #[returns(IsDenomMintableResponse)]
+    IsDenomMintable { denom: String },
+
+    #[returns(Option<Uint128>)]
+    GetMaxSupply { denom: String },
+
+    // Everything ever minted of a denom through this contract. Unlike the supply, burns do not lower it.
+    #[returns(Uint128)]
+    GetMintedTotal { denom: String },
+
+    // The bank metadata of a managed denom, None if it was never set
+    #[returns(Option<DenomMetadata>)]
+    GetDenomMetadata { denom: String },
+
+    // Runs the checks of a Mint from `sender` without minting, failing with the error the Mint would.
+    // The mint fee is returned, but the funds to pay it are not checked.
+    #[returns(SimulateMintResponse)]
+    SimulateMint {
+        sender: String,
+        address: String,
+        denoms: Vec<Coin>,
+    },
+
+    // How Burn would split these funds, failing with the error the Burn would (e.g. in strict burn mode)
+    #[returns(SimulateBurnResponse)]
+    SimulateBurn { funds: Vec<Coin> },
+
+    // The address a denom is delegated to with SetDenomManager
+    #[returns(Option<String>)]
+    GetDenomManager { denom: String },
+
+    #[returns(Option<Uint128>)]
+    GetMaxMintPerTx { denom: String },
+
+    #[returns(Option<Coin>)]
+    GetMintFee {},
+
+    // The configured limit only, see GetMintAllowance for what is left of the current window
+    #[returns(Option<RateLimit>)]
+    GetRateLimit { denom: String },
+
+    // What is left of the current rate limit window, fails for denoms without a rate limit
+    #[returns(MintAllowanceResponse)]
+    GetMintAllowance { denom: String },
+
+    // Current total supply of a managed denom
+    #[returns(Coin)]
+    GetSupply { denom: String },
+
+    #[returns(Vec<Coin>)]
+    GetAllSupplies {},
+
+    // This contract's own balance of each managed denom, leaving out the ones it holds none of
+    #[returns(Vec<Coin>)]
+    GetContractBalances {},
+
     #[returns(Coin)]
     GetBalance { address: String, denom: String },
 
     #[returns(Vec<Coin>)]
     GetAllBalances { address: String },
 }
+
+#[cw_serde]
+pub struct Ownership {
+    // None once management is renounced
+    pub owner: Option<String>,
+    pub pending_owner: Option<String>,
+    pub pending_expiry: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct IsWhitelistedResponse {
+    pub whitelisted: bool,
+}
+
+#[cw_serde]
+pub struct SimulateMintResponse {
+    pub address: String,
+    // total to be minted per denom
+    pub total: Vec<Coin>,
+    pub fee: Option<Coin>,
+}
+
+#[cw_serde]
+pub struct SimulateBurnResponse {
+    pub to_burn: Vec<Coin>,
+    // sent back to the sender (or the treasury)
+    pub to_return: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct FullConfigResponse {
+    pub config: crate::state::Config,
+    pub contract_address: String,
+    pub version: ContractVersion,
+}
+
+#[cw_serde]
+pub enum ConfigField {
+    Managers,
+    Denoms,
+    // every whitelisted address, use GetConfigPage to page through a large whitelist
+    Whitelist,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ConfigFieldsResponse {
+    pub managers: Option<Vec<String>>,
+    pub denoms: Option<Vec<Denom>>,
+    pub whitelist: Option<Vec<String>>,
+}
+
+#[cw_serde]
+pub struct StatsResponse {
+    pub num_whitelisted: u32,
+    pub num_denoms: u32,
+}
+
+#[cw_serde]
+pub struct DenomsByOriginResponse {
+    pub self_created: Vec<String>,
+    pub external: Vec<String>,
+}
+
+#[cw_serde]
+pub struct DenomStatus {
+    pub denom: String,
+    pub is_admin: bool,
+}
+
+#[cw_serde]
+pub struct IsManagedDenomResponse {
+    pub managed: bool,
+}
+
+#[cw_serde]
+pub struct IsPausedResponse {
+    pub paused: bool,
+}
+
+#[cw_serde]
+pub struct IsDenomMintableResponse {
+    pub mintable: bool,
+}
+
+#[cw_serde]
+pub struct MintAllowanceResponse {
+    pub remaining: Uint128,
+    pub resets_at: Timestamp,
+}
+
+// What the voucher signer signs for MintWithVoucher: the sha256 hash of this serialized as
+// compact JSON, with the fields in this order
+#[cw_serde]
+pub struct Voucher {
+    // this contract, so a voucher can not be redeemed on another instance
+    pub contract: String,
+    pub recipient: String,
+    pub denom: String,
+    pub amount: Uint128,
+    pub expiry: Timestamp,
+    pub nonce: String,
+}
+
+// Set as the response data of Mint & MintBatch
+#[cw_serde]
+pub struct MintResponse {
+    // total minted per denom
+    pub total: Vec<Coin>,
+}
+
+// Set as the response data of CreateDenom & CreateAndMint
+#[cw_serde]
+pub struct CreateDenomResponse {
+    // factory/<contract>/<subdenom>
+    pub denom: String,
+}
+
+// Set as the response data of Burn
+#[cw_serde]
+pub struct BurnResponse {
+    pub burned: Vec<Coin>,
+    // non factory funds sent back to the sender (or the treasury)
+    pub returned: Vec<Coin>,
+}
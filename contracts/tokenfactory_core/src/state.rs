@@ -1,11 +1,126 @@
 use cosmwasm_schema::cw_serde;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Binary, Coin, Empty, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
+use juno_tokenfactory_types::{denom::Denom, msg::RateLimit};
 
 #[cw_serde]
 pub struct Config {
-    pub manager: String,
-    pub allowed_mint_addresses: Vec<String>,
-    pub denoms: Vec<String>,
+    // any of these addresses can perform manager actions
+    pub managers: Vec<String>,
+    pub denoms: Vec<Denom>,
+    // when true, no one can mint
+    #[serde(default)]
+    pub paused: bool,
+    // receives non factory funds sent to burn, instead of returning them
+    #[serde(default)]
+    pub treasury: Option<String>,
+    // set once the manager renounces, after which no manager action is possible
+    #[serde(default)]
+    pub renounced: bool,
+    // when true, mints first query that this contract is still the admin of each denom
+    #[serde(default)]
+    pub verify_denom_admin: bool,
+    // charged per mint and forwarded to the treasury
+    #[serde(default)]
+    pub mint_fee: Option<Coin>,
+    // when true, Burn rejects non factory funds instead of returning them
+    #[serde(default)]
+    pub strict_burn: bool,
+    // deposited 1:1 with every mint, see COLLATERAL
+    #[serde(default)]
+    pub collateral_denom: Option<String>,
+    // when true, whitelist additions are pending until another manager confirms them
+    #[serde(default)]
+    pub whitelist_approval: bool,
+    // emitted as the contract_label attribute of every response, to tell instances apart
+    #[serde(default)]
+    pub label: Option<String>,
+    // secp256k1 public key whose signed vouchers anyone can redeem with MintWithVoucher
+    #[serde(default)]
+    pub voucher_signer: Option<Binary>,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+
+#[cw_serde]
+#[derive(Default)]
+pub struct WhitelistEntry {
+    // no longer whitelisted from this time on, never expires when None
+    #[serde(default)]
+    pub expires: Option<Timestamp>,
+}
+
+// Addresses allowed to mint every managed denom
+pub const WHITELIST: Map<&Addr, WhitelistEntry> = Map::new("whitelist");
+
+// The number of WHITELIST entries, so GetStats does not have to iterate them.
+// Only change the whitelist through save_whitelist_entry and remove_whitelist_entry.
+pub const WHITELIST_COUNT: Item<u32> = Item::new("whitelist_count");
+
+#[cw_serde]
+pub struct PendingWhitelistEntry {
+    // the manager who added the address, which can not confirm it while there are other managers
+    pub proposer: String,
+    pub entry: WhitelistEntry,
+}
+
+// Whitelist additions waiting for ConfirmWhitelist, these can not mint yet
+pub const PENDING_WHITELIST: Map<&Addr, PendingWhitelistEntry> = Map::new("pending_whitelist");
+
+// (denom, minter) -> the proposer, for AddDenomMinters waiting for ConfirmWhitelist
+pub const PENDING_DENOM_MINTERS: Map<(&str, &Addr), String> = Map::new("pending_denom_minters");
+
+#[cw_serde]
+pub struct PendingManager {
+    // the manager being replaced once the address accepts
+    pub proposer: String,
+    pub address: String,
+    // only set through UpdateOwnership
+    #[serde(default)]
+    pub expiry: Option<Expiration>,
+}
+
+// Addresses which can not send or receive managed denoms (see SudoMsg::BlockBeforeSend)
+pub const BLOCKLIST: Map<&Addr, Empty> = Map::new("blocklist");
+
+// Set by a manager, cleared once the address accepts the role
+pub const PENDING_MANAGER: Item<PendingManager> = Item::new("pending_manager");
+
+// denom -> the address it is delegated to, next to the contract managers
+pub const DENOM_MANAGERS: Map<&str, String> = Map::new("denom_managers");
+
+// (denom, minter) pairs allowed to mint only that denom
+pub const DENOM_MINTERS: Map<(&str, &Addr), Empty> = Map::new("denom_minters");
+
+// managed denoms which can not be minted until enabled again
+pub const DISABLED_DENOMS: Map<&str, Empty> = Map::new("disabled_denoms");
+
+// mint ids which have already been used
+pub const MINT_IDS: Map<&str, Empty> = Map::new("mint_ids");
+
+// voucher nonces which have already been redeemed
+pub const USED_VOUCHERS: Map<&str, Empty> = Map::new("used_vouchers");
+
+// minted denom -> the amount of the collateral denom deposited for it
+pub const COLLATERAL: Map<&str, Uint128> = Map::new("collateral");
+
+// denom -> the total ever minted through this contract, burns do not lower it
+pub const MINTED_TOTALS: Map<&str, Uint128> = Map::new("minted_totals");
+
+// denom -> the maximum total supply allowed to exist
+pub const MAX_SUPPLY: Map<&str, Uint128> = Map::new("max_supply");
+
+// denom -> the most that can be minted in one transaction
+pub const MAX_MINT_PER_TX: Map<&str, Uint128> = Map::new("max_mint_per_tx");
+
+#[cw_serde]
+pub struct MintWindow {
+    pub started_at: Timestamp,
+    pub minted: Uint128,
+}
+
+// denom -> how much can be minted per window
+pub const RATE_LIMITS: Map<&str, RateLimit> = Map::new("rate_limits");
+// denom -> the amount minted in the current window
+pub const MINT_WINDOWS: Map<&str, MintWindow> = Map::new("mint_windows");
@@ -0,0 +1,98 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use cosmwasm_std::{StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A tokenfactory denom in the format factory/<creator>/<subdenom>.
+///
+/// It is (de)serialized as a plain string, but deserializing checks the format,
+/// so a Denom taken from a message or from state is always well formed.
+///
+/// ```
+/// use juno_tokenfactory_types::denom::Denom;
+///
+/// let denom = Denom::try_new("factory/juno1xxx/test").unwrap();
+/// assert_eq!(denom.creator(), "juno1xxx");
+/// assert_eq!(denom.subdenom(), "test");
+///
+/// assert!(Denom::try_new("ujuno").is_err());
+/// assert!(Denom::try_new("factory/juno1xxx").is_err());
+/// assert!(Denom::try_new("factory//test").is_err());
+/// assert!(Denom::try_new("factory/juno1xxx/a/b").is_err());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(try_from = "String", into = "String")]
+pub struct Denom(String);
+
+impl Denom {
+    pub fn try_new(denom: impl Into<String>) -> StdResult<Self> {
+        let denom: String = denom.into();
+
+        if !denom.starts_with("factory/") {
+            return Err(StdError::generic_err(format!(
+                "Invalid denom {denom:?}: must start with 'factory/'"
+            )));
+        }
+
+        let segments: Vec<&str> = denom.split('/').collect();
+        if segments.len() != 3 || segments.iter().any(|s| s.is_empty()) {
+            return Err(StdError::generic_err(format!(
+                "Invalid denom {denom:?}: must be in the format 'factory/<creator>/<subdenom>'"
+            )));
+        }
+
+        Ok(Denom(denom))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn creator(&self) -> &str {
+        self.0.split('/').nth(1).unwrap_or_default()
+    }
+
+    pub fn subdenom(&self) -> &str {
+        self.0.split('/').nth(2).unwrap_or_default()
+    }
+}
+
+impl TryFrom<String> for Denom {
+    type Error = StdError;
+
+    fn try_from(denom: String) -> StdResult<Self> {
+        Denom::try_new(denom)
+    }
+}
+
+impl From<Denom> for String {
+    fn from(denom: Denom) -> Self {
+        denom.0
+    }
+}
+
+impl AsRef<str> for Denom {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Denom {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<String> for Denom {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+impl fmt::Display for Denom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -1 +1,2 @@
+pub mod denom;
 pub mod msg;
@@ -1,31 +1,99 @@
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Binary, Coin, Timestamp, Uint128};
+use cw_utils::Expiration;
 // use token_bindings::Metadata;
+
+use crate::denom::Denom;
 use token_bindings::Metadata;
 
 #[cw_serde]
 pub enum ExecuteMsg {
     // == ANYONE ==
+    // Burns the managed denoms sent along and returns the rest. The burns are always sent before the returns.
     Burn {},
 
     // == WHITELIST ==
     // Mints actual tokens to an address (only whitelisted addresses can do this)
+    // A `mint_id` can only ever be used once, so a resubmitted request is rejected instead of minting twice.
+    // `memo` is added to the response attributes for off-chain reconciliation.
     Mint {
         address: String,
         denom: Vec<Coin>,
+        mint_id: Option<String>,
+        memo: Option<String>,
+    },
+
+    // Mints to this contract's own balance, e.g. for later distribution
+    MintToSelf {
+        denom: Coin,
+    },
+
+    // Mints to this contract, then executes `msg` on `contract` with the minted tokens attached (like CW20 Send)
+    MintAndCall {
+        contract: String,
+        denom: Coin,
+        msg: Binary,
+    },
+
+    // Mints to many addresses in a single message
+    MintBatch {
+        mints: Vec<BatchMint>,
+    },
+
+    // == ANYONE, WITH A VOUCHER ==
+    // Mints to `recipient` with a voucher signed by the voucher signer (see SetVoucherSigner),
+    // so the signer does not have to submit each mint. Each nonce can only be redeemed once.
+    MintWithVoucher {
+        recipient: String,
+        denom: Denom,
+        amount: Uint128,
+        expiry: Timestamp,
+        nonce: String,
+        signature: Binary,
     },
 
     // == MANAGER ==
+    // Creates factory/<contract>/<subdenom> with this contract as the admin & starts managing it
+    CreateDenom {
+        subdenom: String,
+    },
+    // Manages factory/<contract>/<subdenom> for each subdenom, creating the ones which do not exist yet.
+    // Already managed denoms are skipped, so it is safe to send again.
+    EnsureDenoms {
+        subdenoms: Vec<String>,
+    },
+    // Creates a denom like CreateDenom, then mints to each (address, amount) right after it is created
+    CreateAndMint {
+        subdenom: String,
+        initial_mint: Vec<(String, Uint128)>,
+    },
+
+    // Burns a managed denom from any address using the contract's denom admin powers
     BurnFrom {
         from: String,
         denom: Coin,
     },
 
     TransferAdmin {
-        denom: String,
+        denom: Denom,
         new_address: String,
     },
+    // Starts managing a denom whose admin was already given to this contract outside of it.
+    // The admin is set to this contract again, so this fails unless it really is the admin.
+    ReclaimAdmin {
+        denom: Denom,
+    },
+    // (denom, new_address) pairs, all transferred in the same transaction
+    TransferAdminBatch {
+        transfers: Vec<(Denom, String)>,
+    },
+    // Wind down: transfers the admin of every managed denom to `new_admin` and stops managing them.
+    // With `pause` set, minting is also halted.
+    RelinquishAll {
+        new_admin: String,
+        pause: Option<bool>,
+    },
 
     ForceTransfer {
         from: String,
@@ -34,23 +102,222 @@ pub enum ExecuteMsg {
     },
 
     SetMetadata {
-        denom: String,
+        denom: Denom,
         metadata: Metadata,
     },
 
+    // Contract called by the chain (sudo) before every transfer of the denom
+    SetBeforeSendHook {
+        denom: Denom,
+        cosmwasm_address: String,
+    },
+    ClearBeforeSendHook {
+        denom: Denom,
+    },
+
     // Could be a DAO, normal contract, or CW4
     // Future: should we specify what name/denom an address can mint?
     AddWhitelist {
         addresses: Vec<String>,
     },
+    // Whitelists the addresses until `expires`, e.g. for a time boxed campaign
+    AddWhitelistWithExpiry {
+        addresses: Vec<String>,
+        expires: Timestamp,
+    },
     RemoveWhitelist {
         addresses: Vec<String>,
     },
+    // Replaces the entire whitelist with these addresses
+    SetWhitelist {
+        addresses: Vec<String>,
+    },
+    // When enabled, the additions above only become active once confirmed with ConfirmWhitelist
+    SetWhitelistApproval {
+        enabled: bool,
+    },
+    // Activates pending additions. With several managers, the one who added an address can not confirm it.
+    // With `denom` set, the pending AddDenomMinters of that denom are confirmed instead.
+    ConfirmWhitelist {
+        addresses: Vec<String>,
+        denom: Option<Denom>,
+    },
 
+    // Addresses which can not send or receive managed denoms, once this contract is their before send hook
+    AddBlocklist {
+        addresses: Vec<String>,
+    },
+    RemoveBlocklist {
+        addresses: Vec<String>,
+    },
+
+    // Delegates SetMetadata and the denom minters of a single denom to another address. None removes it.
+    SetDenomManager {
+        denom: Denom,
+        address: Option<String>,
+    },
+
+    // Addresses which may only mint a single denom, in addition to the global whitelist above
+    // Managers and the denom's manager can change these
+    AddDenomMinters {
+        denom: Denom,
+        addresses: Vec<String>,
+    },
+    RemoveDenomMinters {
+        denom: Denom,
+        addresses: Vec<String>,
+    },
+
+    // Denoms which are already managed are skipped, unless `strict` is set, which fails instead
     AddDenom {
-        denoms: Vec<String>,
+        denoms: Vec<Denom>,
+        strict: Option<bool>,
     },
+    // Stops tracking the denoms. With `new_admin` set, the denom admin of each removed denom is also
+    // transferred to it (e.g. a burn address), so this contract no longer controls them.
     RemoveDenom {
-        denoms: Vec<String>,
+        denoms: Vec<Denom>,
+        new_admin: Option<String>,
+    },
+    // Deletes everything still stored for a removed denom, including its minted total.
+    // Denoms can not be removed while collateral is held for them, so none is left to purge.
+    PurgeDenomState {
+        denom: Denom,
+    },
+
+    // Caps the total supply of a denom. Can only ever be lowered once set.
+    SetMaxSupply {
+        denom: Denom,
+        max_supply: Uint128,
+    },
+
+    // Largest amount of a denom a single Mint or MintBatch can mint. None removes the limit.
+    SetMaxMintPerTx {
+        denom: Denom,
+        amount: Option<Uint128>,
     },
+
+    // Limits how much of a denom can be minted per window. None removes the limit.
+    SetRateLimit {
+        denom: Denom,
+        limit: Option<RateLimit>,
+    },
+
+    // Where non factory funds sent to Burn are forwarded. None returns them to the sender.
+    SetTreasury {
+        address: Option<String>,
+    },
+
+    // Fee the sender must attach to every mint (per recipient for MintBatch). None removes the fee.
+    // Requires a treasury to be set, which receives the fee.
+    SetMintFee {
+        fee: Option<Coin>,
+    },
+
+    // Mints must deposit this denom 1:1 with the total amount minted, which is held by the contract.
+    // Burning releases the collateral of the burned denom proportionally to the supply burned.
+    // Can only be changed while no collateral is held. None disables it.
+    SetCollateralDenom {
+        denom: Option<String>,
+    },
+
+    // Costs a query per minted denom, but fails early when the denom admin was moved elsewhere.
+    SetVerifyDenomAdmin {
+        enabled: bool,
+    },
+
+    // Makes Burn reject non factory funds instead of returning them
+    SetStrictBurn {
+        enabled: bool,
+    },
+
+    // The secp256k1 public key which signs MintWithVoucher vouchers. None stops accepting them.
+    SetVoucherSigner {
+        pubkey: Option<Binary>,
+    },
+
+    // Added as the contract_label attribute to every response. None removes it.
+    SetLabel {
+        label: Option<String>,
+    },
+
+    // Sends the contract's whole balance of a non managed denom, e.g. funds sent here by mistake.
+    // Collateral held for mints is never swept.
+    Sweep {
+        denom: String,
+        to_address: String,
+    },
+
+    // Forgets used mint ids so their storage is freed. They can be used again afterwards.
+    PruneMintIds {
+        ids: Vec<String>,
+    },
+
+    // Halts all minting. Burning is still allowed.
+    Pause {},
+    Unpause {},
+
+    // Halts minting of a single denom, keeping its limits and grants. Burning is still allowed.
+    DisableDenom {
+        denom: Denom,
+    },
+    EnableDenom {
+        denom: Denom,
+    },
+
+    // Managers can add or remove other managers. The last manager can not be removed.
+    AddManager {
+        address: String,
+    },
+    RemoveManager {
+        address: String,
+    },
+
+    // Two step manager transfer. The proposed address must accept before it replaces the proposing manager.
+    ProposeNewManager {
+        address: String,
+    },
+    // One step alternative to ProposeNewManager, the sender is replaced by `new_manager` immediately.
+    // A mistyped address can not be recovered, so `confirm_no_recovery` must be true.
+    SetManager {
+        new_manager: String,
+        confirm_no_recovery: bool,
+    },
+    // Permanently gives up management of the contract. This is irreversible, so `confirm` must be true.
+    // Transfer the denom admins away first if the denoms should not stay locked in this contract forever.
+    RenounceManager {
+        confirm: bool,
+    },
+
+    // == PENDING MANAGER ==
+    AcceptManager {},
+
+    // cw-ownable compatible interface to the manager transfer above
+    UpdateOwnership(OwnershipAction),
+}
+
+#[cw_serde]
+pub enum OwnershipAction {
+    // ProposeNewManager, which can no longer be accepted once `expiry` is reached
+    TransferOwnership {
+        new_owner: String,
+        expiry: Option<Expiration>,
+    },
+    // AcceptManager
+    AcceptOwnership,
+    // RenounceManager, sending this is the confirmation
+    RenounceOwnership,
+}
+
+#[cw_serde]
+pub struct BatchMint {
+    pub address: String,
+    pub denom: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct RateLimit {
+    // max amount that can be minted within a single window
+    pub amount: Uint128,
+    pub window_seconds: u64,
 }
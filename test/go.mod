@@ -15,6 +15,7 @@ replace (
 )
 
 require (
+	cosmossdk.io/math v1.1.2
 	github.com/CosmWasm/wasmd v0.41.0
 	github.com/CosmosContracts/juno/v17 v17.0.0
 	github.com/cosmos/cosmos-sdk v0.47.5
@@ -36,7 +37,6 @@ require (
 	cosmossdk.io/depinject v1.0.0-alpha.4 // indirect
 	cosmossdk.io/errors v1.0.0 // indirect
 	cosmossdk.io/log v1.2.1 // indirect
-	cosmossdk.io/math v1.1.2 // indirect
 	cosmossdk.io/tools/rosetta v0.2.1 // indirect
 	filippo.io/edwards25519 v1.0.0 // indirect
 	github.com/99designs/go-keychain v0.0.0-20191008050251-8e49817e8af4 // indirect
@@ -8,6 +8,7 @@ import (
 	"testing"
 
 	"github.com/cosmos/cosmos-sdk/crypto/keyring"
+	sdk "github.com/cosmos/cosmos-sdk/types"
 	"github.com/strangelove-ventures/interchaintest/v7/chain/cosmos"
 	"github.com/strangelove-ventures/interchaintest/v7/ibc"
 	"github.com/strangelove-ventures/interchaintest/v7/testutil"
@@ -30,6 +31,16 @@ func SetupContract(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain,
 	return codeId, contractAddr
 }
 
+// InstantiateContractTx instantiates like chain.InstantiateContract, but returns the tx so its events can be checked
+func InstantiateContractTx(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, keyname, codeId, message string) *sdk.TxResponse {
+	txHash, err := chain.GetNode().ExecTx(ctx, keyname, "wasm", "instantiate", codeId, message, "--label", "contract"+codeId, "--no-admin")
+	require.NoError(t, err)
+
+	txRes, err := chain.GetTransaction(txHash)
+	require.NoError(t, err)
+	return txRes
+}
+
 func InstantiateMsgWithGas(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, user ibc.Wallet, codeId, gas, coinAmt, message string) {
 	// TODO: ictest does not allow --gas=auto for init yet. So still stuck with this ugh
 	cmd := []string{"junod", "tx", "wasm", "instantiate", codeId, message,
@@ -1,11 +1,22 @@
 package test
 
 import (
+	"encoding/base64"
+	"encoding/json"
 	"fmt"
+	"strconv"
+	"strings"
 	"testing"
+	"time"
 
+	"cosmossdk.io/math"
+	"github.com/cosmos/cosmos-sdk/crypto/keys/secp256k1"
+	"github.com/cosmos/cosmos-sdk/types/bech32"
 	"github.com/strangelove-ventures/interchaintest/v7"
 	"github.com/strangelove-ventures/interchaintest/v7/chain/cosmos"
+	"github.com/strangelove-ventures/interchaintest/v7/ibc"
+	"github.com/strangelove-ventures/interchaintest/v7/testutil"
+	"github.com/stretchr/testify/require"
 	"gotest.tools/assert"
 
 	helpers "github.com/CosmosContracts/tokenfactory-contracts/helpers"
@@ -38,10 +49,66 @@ func TestBasicContract(t *testing.T) {
 	tfCoreCodeId, tfCoreContractAddr := helpers.SetupContract(t, ctx, juno, user.KeyName(), TF_CORE_FILE, tfCoreMsg)
 
 	assert.Assert(t, len(tfCoreContractAddr) > 0)
+
+	// invalid instantiate messages are rejected
+	for _, badMsg := range []string{
+		fmt.Sprintf(`{"manager":" ","allowed_mint_addresses":[],"existing_denoms":["%s"]}`, tfDenom),
+		fmt.Sprintf(`{"allowed_mint_addresses":["%s","%s"],"existing_denoms":["%s"]}`, uaddr, uaddr, tfDenom),
+		fmt.Sprintf(`{"allowed_mint_addresses":[],"existing_denoms":["%s","%s"]}`, tfDenom, tfDenom),
+		// metadata for a denom which is not listed, or with another base
+		fmt.Sprintf(`{"allowed_mint_addresses":[],"existing_denoms":["%s"],"denom_metadata":[["factory/%s/other",{"denom_units":[]}]]}`, tfDenom, uaddr),
+		fmt.Sprintf(`{"allowed_mint_addresses":[],"existing_denoms":["%s"],"denom_metadata":[["%s",{"denom_units":[],"base":"ujuno"}]]}`, tfDenom, tfDenom),
+		// minters for a denom which is not listed, an invalid or a duplicate minter
+		fmt.Sprintf(`{"allowed_mint_addresses":[],"existing_denoms":["%s"],"denom_minters":[["factory/%s/other",["%s"]]]}`, tfDenom, uaddr, uaddr2),
+		fmt.Sprintf(`{"allowed_mint_addresses":[],"existing_denoms":["%s"],"denom_minters":[["%s",["notanaddress"]]]}`, tfDenom, tfDenom),
+		fmt.Sprintf(`{"allowed_mint_addresses":[],"existing_denoms":["%s"],"denom_minters":[["%s",["%s","%s"]]]}`, tfDenom, tfDenom, uaddr2, uaddr2),
+	} {
+		if _, err := juno.InstantiateContract(ctx, user.KeyName(), tfCoreCodeId, badMsg, true); err == nil {
+			t.Fatalf("instantiate should fail: %s", badMsg)
+		}
+	}
+
+	// per denom minters can be seeded at instantiate, and only mint their own denom
+	seededDenom := helpers.CreateTokenFactoryDenom(t, ctx, juno, user, "seeded")
+	otherDenom := helpers.CreateTokenFactoryDenom(t, ctx, juno, user, "unseeded")
+	seededMsg := fmt.Sprintf(`{"allowed_mint_addresses":[],"existing_denoms":["%s","%s"],"denom_minters":[["%s",["%s"]]]}`, seededDenom, otherDenom, seededDenom, uaddr2)
+	seededAddr, instErr := juno.InstantiateContract(ctx, user.KeyName(), tfCoreCodeId, seededMsg, true)
+	if instErr != nil {
+		t.Fatal(instErr)
+	}
+	helpers.TransferTokenFactoryAdmin(t, ctx, juno, user, seededAddr, seededDenom)
+	helpers.TransferTokenFactoryAdmin(t, ctx, juno, user, seededAddr, otherDenom)
+
+	seededMint := fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"3"}]}}`, uaddr2, seededDenom)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), seededAddr, seededMint); err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr2, seededDenom, 3)
+	otherMint := fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"3"}]}}`, uaddr2, otherDenom)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), seededAddr, otherMint); err == nil {
+		t.Fatal("a seeded minter should not mint other denoms")
+	}
+
+	// the initial config is part of the instantiate attributes
+	initMsg := fmt.Sprintf(`{"allowed_mint_addresses":["%s","%s"],"existing_denoms":["%s"]}`, uaddr, uaddr2, tfDenom)
+	initRes := helpers.InstantiateContractTx(t, ctx, juno, user.KeyName(), tfCoreCodeId, initMsg)
+	assert.Equal(t, GetWasmAttribute(initRes, "manager"), uaddr)
+	assert.Equal(t, GetWasmAttribute(initRes, "denom_count"), "1")
+	assert.Equal(t, GetWasmAttribute(initRes, "whitelist_count"), "2")
+
 	res := GetContractConfig(t, ctx, juno, tfCoreContractAddr)
-	assert.Assert(t, len(res.Data.AllowedMintAddresses) == 1)
+	assert.Assert(t, len(GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data) == 1)
 	assert.Equal(t, res.Data.Denoms[0], tfDenom)
 
+	// only the requested config fields are returned
+	fields := GetContractConfigFields(t, ctx, juno, tfCoreContractAddr, "denoms").Data
+	assert.DeepEqual(t, fields.Denoms, []string{tfDenom})
+	assert.Assert(t, fields.Managers == nil && fields.Whitelist == nil)
+	fields = GetContractConfigFields(t, ctx, juno, tfCoreContractAddr, "managers", "whitelist").Data
+	assert.DeepEqual(t, fields.Managers, []string{uaddr})
+	assert.DeepEqual(t, fields.Whitelist, []string{uaddr})
+	assert.Assert(t, fields.Denoms == nil)
+
 	// transfer admin to the contract
 	helpers.TransferTokenFactoryAdmin(t, ctx, juno, user, tfCoreContractAddr, tfDenom)
 	denomAdmin = helpers.GetTokenFactoryAdmin(t, ctx, juno, tfDenom)
@@ -59,34 +126,523 @@ func TestBasicContract(t *testing.T) {
 	balRes := GetCoreContractUserBalance(t, ctx, juno, tfCoreContractAddr, uaddr, tfDenom)
 	assert.Equal(t, balRes.Data.Amount, "100")
 
+	// duplicate denoms are combined into a single mint
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"5"},{"denom":"%s","amount":"3"}]}}`, uaddr, tfDenom, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 108)
+	assert.Equal(t, GetContractMintedTotal(t, ctx, juno, tfCoreContractAddr, tfDenom), "108")
+
+	// zero amounts are rejected
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"0"}]}}`, uaddr, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("minting a zero amount should fail")
+	}
+
+	// SIMULATE MINT matches what the mint does, without minting
+	sim := GetContractSimulateMint(t, ctx, juno, tfCoreContractAddr, uaddr, uaddr2, []Coin{{Denom: tfDenom, Amount: "4"}, {Denom: tfDenom, Amount: "3"}})
+	require.NoError(t, sim.Err)
+	assert.Equal(t, len(sim.Data.Total), 1)
+	assert.Equal(t, sim.Data.Total[0].Amount, "7")
+	AssertBalance(t, ctx, juno, uaddr2, tfDenom, 0)
+
+	// user2 is not whitelisted, so both fail
+	sim = GetContractSimulateMint(t, ctx, juno, tfCoreContractAddr, uaddr2, uaddr2, []Coin{{Denom: tfDenom, Amount: "1"}})
+	require.Error(t, sim.Err)
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"1"}]}}`, uaddr2, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("minting from a non whitelisted address should fail")
+	}
+
+	// MINT FEE (paid to user2 as the treasury)
+	nativeDenom := juno.Config().Denom
+	msg = fmt.Sprintf(`{"set_treasury":{"address":"%s"}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	msg = fmt.Sprintf(`{"set_mint_fee":{"fee":{"denom":"%s","amount":"10"}}}`, nativeDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+
+	mintOne := fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"1"}]}}`, uaddr, tfDenom)
+
+	// exact fee
+	helpers.ExecuteMsgWithAmount(t, ctx, juno, user, tfCoreContractAddr, fmt.Sprintf("10%s", nativeDenom), mintOne)
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 109)
+	AssertBalance(t, ctx, juno, uaddr2, nativeDenom, 100_000_010)
+
+	// insufficient fee, nothing is minted
+	_, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, mintOne, "--amount", fmt.Sprintf("5%s", nativeDenom))
+	require.ErrorContains(t, err, fmt.Sprintf("10%s is required but 5%s was sent", nativeDenom, nativeDenom))
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, mintOne)
+	require.ErrorContains(t, err, "No funds were sent")
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 109)
+	AssertBalance(t, ctx, juno, uaddr2, nativeDenom, 100_000_010)
+
+	// overpaying only charges the fee
+	helpers.ExecuteMsgWithAmount(t, ctx, juno, user, tfCoreContractAddr, fmt.Sprintf("25%s", nativeDenom), mintOne)
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 110)
+	AssertBalance(t, ctx, juno, uaddr2, nativeDenom, 100_000_020)
+	AssertBalance(t, ctx, juno, tfCoreContractAddr, nativeDenom, 0)
+
+	// remove the fee and treasury again
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_mint_fee":{"fee":null}}`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_treasury":{"address":null}}`); err != nil {
+		t.Fatal(err)
+	}
+
+	// without a fee, funds sent along are rejected instead of kept
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, mintOne, "--amount", fmt.Sprintf("5%s", nativeDenom))
+	require.ErrorContains(t, err, fmt.Sprintf("Unexpected funds of %q", nativeDenom))
+
+	// MINT IDS
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"1"}],"mint_id":"job-1"}}`, uaddr, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 111)
+
+	// resubmitting the same id does not mint again
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("reusing a mint id should fail")
+	}
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 111)
+
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"1"}],"mint_id":"job-2"}}`, uaddr, tfDenom)
+	txRes, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 112)
+	assert.Equal(t, GetWasmAttribute(txRes, "memo"), "")
+
+	// MEMO
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"1"}],"memo":"bridge deposit 42"}}`, uaddr, tfDenom)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 113)
+	assert.Equal(t, GetWasmAttribute(txRes, "memo"), "bridge deposit 42")
+
+	// DISABLED DENOMS can not be minted, but can still be burned
+	msg = fmt.Sprintf(`{"disable_denom":{"denom":"%s"}}`, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, mintOne); err == nil {
+		t.Fatal("minting a disabled denom should fail")
+	}
+	sim = GetContractSimulateMint(t, ctx, juno, tfCoreContractAddr, uaddr, uaddr, []Coin{{Denom: tfDenom, Amount: "1"}})
+	require.ErrorContains(t, sim.Err, "is disabled")
+	assert.Assert(t, !GetContractIsDenomMintable(t, ctx, juno, tfCoreContractAddr, tfDenom))
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 113)
+
+	// burn it again so the balances below are unchanged
+	helpers.ExecuteMsgWithAmount(t, ctx, juno, user, tfCoreContractAddr, fmt.Sprintf("1%s", tfDenom), `{"burn":{}}`)
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 112)
+
+	msg = fmt.Sprintf(`{"enable_denom":{"denom":"%s"}}`, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, mintOne); err != nil {
+		t.Fatal(err)
+	}
+	helpers.ExecuteMsgWithAmount(t, ctx, juno, user, tfCoreContractAddr, fmt.Sprintf("1%s", tfDenom), `{"burn":{}}`)
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 112)
+
+	// every mint entry point applies the same gates
+	unmanaged := fmt.Sprintf("factory/%s/unmanaged", uaddr)
+	sim = GetContractSimulateMint(t, ctx, juno, tfCoreContractAddr, uaddr, uaddr, []Coin{{Denom: unmanaged, Amount: "1"}})
+	require.ErrorContains(t, sim.Err, "not managed by this contract")
+	assert.Assert(t, !GetContractIsDenomMintable(t, ctx, juno, tfCoreContractAddr, unmanaged))
+	assert.Assert(t, GetContractIsDenomMintable(t, ctx, juno, tfCoreContractAddr, tfDenom))
+	assert.Assert(t, !GetContractIsPaused(t, ctx, juno, tfCoreContractAddr))
+
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"pause":{}}`); err != nil {
+		t.Fatal(err)
+	}
+	sim = GetContractSimulateMint(t, ctx, juno, tfCoreContractAddr, uaddr, uaddr, []Coin{{Denom: tfDenom, Amount: "1"}})
+	require.ErrorContains(t, sim.Err, "Minting is paused")
+	assert.Assert(t, GetContractIsPaused(t, ctx, juno, tfCoreContractAddr))
+	assert.Assert(t, !GetContractIsDenomMintable(t, ctx, juno, tfCoreContractAddr, tfDenom))
+	msg = fmt.Sprintf(`{"mint_batch":{"mints":[{"address":"%s","denom":[{"denom":"%s","amount":"1"}]}]}}`, uaddr, tfDenom)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	require.ErrorContains(t, err, "Minting is paused")
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"unpause":{}}`); err != nil {
+		t.Fatal(err)
+	}
+	assert.Assert(t, !GetContractIsPaused(t, ctx, juno, tfCoreContractAddr))
+
+	// BURN
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"burn":{}}`)
+	require.ErrorContains(t, err, "No funds were sent")
+
+	// burns below do not lower the total ever minted
+	mintedTotal := GetContractMintedTotal(t, ctx, juno, tfCoreContractAddr, tfDenom)
+
+	// the preview matches the burn below
+	simBurn := GetContractSimulateBurn(t, ctx, juno, tfCoreContractAddr, []Coin{{Denom: tfDenom, Amount: "2"}, {Denom: nativeDenom, Amount: "5"}})
+	assert.Equal(t, len(simBurn.Data.ToBurn), 1)
+	assert.Equal(t, simBurn.Data.ToBurn[0].Amount, "2")
+	assert.Equal(t, len(simBurn.Data.ToReturn), 1)
+	assert.Equal(t, simBurn.Data.ToReturn[0].Denom, nativeDenom)
+
+	// non factory funds are returned
+	burnFunds := fmt.Sprintf("2%s,5%s", tfDenom, nativeDenom)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"burn":{}}`, "--amount", burnFunds)
+	if err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 110)
+	AssertBalance(t, ctx, juno, tfCoreContractAddr, nativeDenom, 0)
+	assert.Equal(t, GetWasmAttribute(txRes, "total_burned"), fmt.Sprintf("2:%s", tfDenom))
+	assert.Equal(t, GetWasmAttribute(txRes, "total_returned"), fmt.Sprintf("5:%s", nativeDenom))
+
+	// the burn is always executed before the funds are returned
+	burnIdx := GetEventIndex(txRes, "tf_burn", "amount", fmt.Sprintf("2%s", tfDenom))
+	returnIdx := GetEventIndex(txRes, "transfer", "amount", fmt.Sprintf("5%s", nativeDenom))
+	assert.Assert(t, burnIdx >= 0 && returnIdx >= 0)
+	assert.Assert(t, burnIdx < returnIdx)
+
+	// in strict mode they are rejected, so nothing is burned
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_strict_burn":{"enabled":true}}`); err != nil {
+		t.Fatal(err)
+	}
+	helpers.ExecuteMsgWithAmount(t, ctx, juno, user, tfCoreContractAddr, burnFunds, `{"burn":{}}`)
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 110)
+
+	helpers.ExecuteMsgWithAmount(t, ctx, juno, user, tfCoreContractAddr, fmt.Sprintf("2%s", tfDenom), `{"burn":{}}`)
+	AssertBalance(t, ctx, juno, uaddr, tfDenom, 108)
+	assert.Equal(t, GetContractMintedTotal(t, ctx, juno, tfCoreContractAddr, tfDenom), mintedTotal)
+
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_strict_burn":{"enabled":false}}`); err != nil {
+		t.Fatal(err)
+	}
+
 	// Whitelist
 	// Try to add user to contract whitelist again.
 	msg = fmt.Sprintf(`{"add_whitelist":{"addresses":["%s"]}}`, uaddr)
-	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	if err != nil {
 		t.Fatal(err)
 	}
 
-	// still is one
-	res = GetContractConfig(t, ctx, juno, tfCoreContractAddr)
-	assert.Assert(t, len(res.Data.AllowedMintAddresses) == 1)
+	// still is one, and nothing is reported as added
+	assert.Assert(t, len(GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data) == 1)
+	assert.Equal(t, GetWasmAttribute(txRes, "added"), "")
 
 	// add a diff user
+	msg = fmt.Sprintf(`{"add_whitelist":{"addresses":["%s","%s"]}}`, uaddr, uaddr2)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	assert.Assert(t, len(GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data) == 2)
+	assert.Equal(t, GetWasmAttribute(txRes, "added"), uaddr2)
+	assert.Equal(t, GetContractStats(t, ctx, juno, tfCoreContractAddr).Data.NumWhitelisted, uint32(2))
+
+	// remove user2 from whitelist
+	msg = fmt.Sprintf(`{"remove_whitelist":{"addresses":["%s"]}}`, uaddr2)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "removed"), uaddr2)
+
+	assert.Assert(t, len(GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data) == 1)
+	assert.Equal(t, GetContractStats(t, ctx, juno, tfCoreContractAddr).Data.NumWhitelisted, uint32(1))
+
+	// replace the whole whitelist with only user2 (listed twice)
+	msg = fmt.Sprintf(`{"set_whitelist":{"addresses":["%s","%s"]}}`, uaddr2, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	whitelist := GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data
+	assert.Equal(t, len(whitelist), 1)
+	assert.Equal(t, whitelist[0], uaddr2)
+	assert.Equal(t, GetContractStats(t, ctx, juno, tfCoreContractAddr).Data.NumWhitelisted, uint32(1))
+
+	// and back to user
+	msg = fmt.Sprintf(`{"set_whitelist":{"addresses":["%s"]}}`, uaddr)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data[0], uaddr)
+
+	// a temporary grant can mint until it expires
+	expires := time.Now().Add(20 * time.Second)
+	msg = fmt.Sprintf(`{"add_whitelist_with_expiry":{"addresses":["%s"],"expires":"%d"}}`, uaddr2, expires.UnixNano())
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"1"}]}}`, uaddr2, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	// burn it again, so the balances below are unchanged
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, `{"burn":{}}`, "--amount", fmt.Sprintf("1%s", tfDenom)); err != nil {
+		t.Fatal(err)
+	}
+
+	time.Sleep(time.Until(expires))
+	if err := testutil.WaitForBlocks(ctx, 2, juno); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("an expired whitelist entry should not be able to mint")
+	}
+	msg = fmt.Sprintf(`{"remove_whitelist":{"addresses":["%s"]}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+
+	// WHITELIST APPROVAL additions stay pending until a different manager confirms them
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_whitelist_approval":{"enabled":true}}`); err != nil {
+		t.Fatal(err)
+	}
+	msg = fmt.Sprintf(`{"add_manager":{"address":"%s"}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
 	msg = fmt.Sprintf(`{"add_whitelist":{"addresses":["%s"]}}`, uaddr2)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "pending"), uaddr2)
+	assert.Equal(t, len(GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data), 1)
+
+	mintMsg := fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"1"}]}}`, uaddr2, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, mintMsg); err == nil {
+		t.Fatal("a pending whitelist entry should not be able to mint")
+	}
+
+	msg = fmt.Sprintf(`{"confirm_whitelist":{"addresses":["%s"]}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("the manager who added an entry should not be able to confirm it")
+	}
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, mintMsg); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, `{"burn":{}}`, "--amount", fmt.Sprintf("1%s", tfDenom)); err != nil {
+		t.Fatal(err)
+	}
+
+	// minters of a single denom need the same confirmation
+	denomMinter, err := bech32.ConvertAndEncode("juno", []byte("pending-denom-minter"))
+	require.NoError(t, err)
+	msg = fmt.Sprintf(`{"add_denom_minters":{"denom":"%s","addresses":["%s"]}}`, tfDenom, denomMinter)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "pending"), denomMinter)
+	msg = fmt.Sprintf(`{"confirm_whitelist":{"addresses":["%s"]}}`, denomMinter)
+	_, err = juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg)
+	require.ErrorContains(t, err, "is not pending whitelist confirmation")
+	msg = fmt.Sprintf(`{"confirm_whitelist":{"addresses":["%s"],"denom":"%s"}}`, denomMinter, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("the manager who added a denom minter should not be able to confirm it")
+	}
+	txRes, err = juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "confirmed"), denomMinter)
+	msg = fmt.Sprintf(`{"remove_denom_minters":{"denom":"%s","addresses":["%s"]}}`, tfDenom, denomMinter)
 	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
 		t.Fatal(err)
 	}
 
-	res = GetContractConfig(t, ctx, juno, tfCoreContractAddr)
-	assert.Assert(t, len(res.Data.AllowedMintAddresses) == 2)
+	// replacing the whitelist drops proposals for addresses left out of it
+	proposed, err := bech32.ConvertAndEncode("juno", []byte("pending-whitelist-addr"))
+	require.NoError(t, err)
+	msg = fmt.Sprintf(`{"add_whitelist":{"addresses":["%s"]}}`, proposed)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	kept, err := json.Marshal(GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data)
+	require.NoError(t, err)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, fmt.Sprintf(`{"set_whitelist":{"addresses":%s}}`, kept))
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "removed"), proposed)
+	msg = fmt.Sprintf(`{"confirm_whitelist":{"addresses":["%s"]}}`, proposed)
+	_, err = juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg)
+	require.ErrorContains(t, err, "is not pending whitelist confirmation")
 
-	// remove user2 from whitelist
+	// a removed manager's transfer proposal goes with it
+	msg = fmt.Sprintf(`{"propose_new_manager":{"address":"%s"}}`, uaddr)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	msg = fmt.Sprintf(`{"remove_manager":{"address":"%s"}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"accept_manager":{}}`)
+	require.ErrorContains(t, err, "There is no pending manager transfer")
 	msg = fmt.Sprintf(`{"remove_whitelist":{"addresses":["%s"]}}`, uaddr2)
 	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
 		t.Fatal(err)
 	}
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_whitelist_approval":{"enabled":false}}`); err != nil {
+		t.Fatal(err)
+	}
 
-	res = GetContractConfig(t, ctx, juno, tfCoreContractAddr)
-	assert.Assert(t, len(res.Data.AllowedMintAddresses) == 1)
+	// CONFIG PAGE a whitelist larger than a page can be read back in full
+	extra := make([]string, 5)
+	for i := range extra {
+		extra[i], err = bech32.ConvertAndEncode("juno", []byte(fmt.Sprintf("config-page-addr-%04d", i)))
+		require.NoError(t, err)
+	}
+	msg = fmt.Sprintf(`{"add_whitelist":{"addresses":["%s"]}}`, strings.Join(extra, `","`))
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+
+	var paged []string
+	var startAfter *string
+	for {
+		page := GetContractConfigPage(t, ctx, juno, tfCoreContractAddr, "whitelist", startAfter, 2)
+		if len(page) == 0 {
+			break
+		}
+		assert.Assert(t, len(page) <= 2)
+		paged = append(paged, page...)
+		startAfter = &page[len(page)-1]
+	}
+	assert.Equal(t, len(paged), len(extra)+1)
+	assert.DeepEqual(t, paged, GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data)
+	assert.DeepEqual(t, GetContractConfigPage(t, ctx, juno, tfCoreContractAddr, "denoms", nil, 2), []string{tfDenom})
+
+	msg = fmt.Sprintf(`{"remove_whitelist":{"addresses":["%s"]}}`, strings.Join(extra, `","`))
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+
+	// RATE LIMIT allowance within the window, once exhausted and after the window resets
+	msg = fmt.Sprintf(`{"set_rate_limit":{"denom":"%s","limit":{"amount":"5","window_seconds":15}}}`, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetContractMintAllowance(t, ctx, juno, tfCoreContractAddr, tfDenom).Data.Remaining, "5")
+
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"3"}]}}`, uaddr2, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetContractMintAllowance(t, ctx, juno, tfCoreContractAddr, tfDenom).Data.Remaining, "2")
+
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"2"}]}}`, uaddr2, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	allowance := GetContractMintAllowance(t, ctx, juno, tfCoreContractAddr, tfDenom).Data
+	assert.Equal(t, allowance.Remaining, "0")
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("minting past the rate limit should fail")
+	}
+
+	resetsAt, err := strconv.ParseInt(allowance.ResetsAt, 10, 64)
+	require.NoError(t, err)
+	time.Sleep(time.Until(time.Unix(0, resetsAt)))
+	if err := testutil.WaitForBlocks(ctx, 2, juno); err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetContractMintAllowance(t, ctx, juno, tfCoreContractAddr, tfDenom).Data.Remaining, "5")
+
+	// remove the limit and burn the minted tokens again, so the balances below are unchanged
+	msg = fmt.Sprintf(`{"set_rate_limit":{"denom":"%s","limit":null}}`, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, `{"burn":{}}`, "--amount", fmt.Sprintf("5%s", tfDenom)); err != nil {
+		t.Fatal(err)
+	}
+
+	// LABEL is added to every response once set, to tell instances apart
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_label":{"label":"core-main"}}`); err != nil {
+		t.Fatal(err)
+	}
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"pause":{}}`)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "contract_label"), "core-main")
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_label":{"label":null}}`); err != nil {
+		t.Fatal(err)
+	}
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"unpause":{}}`)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "contract_label"), "")
+
+	// VOUCHERS signed by the voucher signer can be redeemed by anyone, once
+	signer := secp256k1.GenPrivKey()
+	msg = fmt.Sprintf(`{"set_voucher_signer":{"pubkey":"%s"}}`, base64.StdEncoding.EncodeToString(signer.PubKey().Bytes()))
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	voucher := Voucher{
+		Contract:  tfCoreContractAddr,
+		Recipient: uaddr2,
+		Denom:     tfDenom,
+		Amount:    "7",
+		Expiry:    strconv.FormatInt(time.Now().Add(10*time.Minute).UnixNano(), 10),
+		Nonce:     "voucher-1",
+	}
+	voucherBz, err := json.Marshal(voucher)
+	require.NoError(t, err)
+	signature, err := signer.Sign(voucherBz)
+	require.NoError(t, err)
+
+	redeem := func(amount string) error {
+		msg := fmt.Sprintf(`{"mint_with_voucher":{"recipient":"%s","denom":"%s","amount":"%s","expiry":"%s","nonce":"%s","signature":"%s"}}`,
+			voucher.Recipient, voucher.Denom, amount, voucher.Expiry, voucher.Nonce, base64.StdEncoding.EncodeToString(signature))
+		_, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg)
+		return err
+	}
+
+	// the amount was changed after signing
+	require.ErrorContains(t, redeem("70"), "not signed by the voucher signer")
+
+	balanceBefore := GetCoreContractUserBalance(t, ctx, juno, tfCoreContractAddr, uaddr2, tfDenom).Data.Amount
+	require.NoError(t, redeem("7"))
+	before, err := strconv.ParseInt(balanceBefore, 10, 64)
+	require.NoError(t, err)
+	AssertBalance(t, ctx, juno, uaddr2, tfDenom, before+7)
+
+	require.ErrorContains(t, redeem("7"), "already redeemed")
+
+	// nonces are stored, so their length is bounded before anything else is checked
+	voucher.Nonce = strings.Repeat("n", 65)
+	require.ErrorContains(t, redeem("7"), "Voucher nonces must be between 1 and 64 bytes")
+
+	// burn it again and stop accepting vouchers
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, `{"burn":{}}`, "--amount", fmt.Sprintf("7%s", tfDenom)); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_voucher_signer":{"pubkey":null}}`); err != nil {
+		t.Fatal(err)
+	}
+
+	// OVERFLOW the minted total of tfDenom can not go past the Uint128 max, which fails instead of panicking
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"340282366920938463463374607431768211455"}]}}`, uaddr2, tfDenom)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	require.ErrorContains(t, err, "Cannot Add with")
 
 	// force transfer 1 token from user to user2
 	msg = fmt.Sprintf(`{"force_transfer":{"from":"%s","to":"%s","denom":{"denom":"%s","amount":"3"}}}`, uaddr, uaddr2, tfDenom)
@@ -101,35 +657,155 @@ func TestBasicContract(t *testing.T) {
 	}
 	AssertBalance(t, ctx, juno, uaddr2, tfDenom, 2)
 
+	// only a manager can burn from others, and never a zero amount
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("burn_from as a non manager should fail")
+	}
+	msg = fmt.Sprintf(`{"burn_from":{"from":"%s","denom":{"denom":"%s","amount":"0"}}}`, uaddr2, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("burn_from with a zero amount should fail")
+	}
+	AssertBalance(t, ctx, juno, uaddr2, tfDenom, 2)
+
+	// BLOCKLIST (the contract is its own before send hook)
+	msg = fmt.Sprintf(`{"set_before_send_hook":{"denom":"%s","cosmwasm_address":"%s"}}`, tfDenom, tfCoreContractAddr)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	msg = fmt.Sprintf(`{"add_blocklist":{"addresses":["%s"]}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+
+	sendOne := ibc.WalletAmount{Address: uaddr2, Denom: tfDenom, Amount: math.NewInt(1)}
+	if err := juno.SendFunds(ctx, user.KeyName(), sendOne); err == nil {
+		t.Fatal("sending to a blocked address should fail")
+	}
+	AssertBalance(t, ctx, juno, uaddr2, tfDenom, 2)
+
+	msg = fmt.Sprintf(`{"remove_blocklist":{"addresses":["%s"]}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	if err := juno.SendFunds(ctx, user.KeyName(), sendOne); err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr2, tfDenom, 3)
+
+	msg = fmt.Sprintf(`{"clear_before_send_hook":{"denom":"%s"}}`, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+
 	// mint a token as user2 to user2 addr
 
+	// denoms in messages are checked when the message is parsed
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, `{"set_max_supply":{"denom":"ujuno","max_supply":"1000"}}`)
+	require.ErrorContains(t, err, "must start with 'factory/'")
+
+	// cap the denom so we can check transfer admin cleans it up
+	msg = fmt.Sprintf(`{"set_max_supply":{"denom":"%s","max_supply":"1000"}}`, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, *GetContractMaxSupply(t, ctx, juno, tfCoreContractAddr, tfDenom).Data, "1000")
+
 	// transfer admin to uaddr2 from contract & remove from being able to mint
 	msg = fmt.Sprintf(`{"transfer_admin":{"denom":"%s","new_address":"%s"}}`, tfDenom, uaddr2)
 	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
 		t.Fatal(err)
 	}
+	assert.Assert(t, GetContractMaxSupply(t, ctx, juno, tfCoreContractAddr, tfDenom).Data == nil)
 
 	denomAdmin = helpers.GetTokenFactoryAdmin(t, ctx, juno, tfDenom)
 	assert.Equal(t, uaddr2, denomAdmin)
 
+	// MANAGERS
+	// the instantiator is the only manager, so it can not be removed
+	msg = fmt.Sprintf(`{"remove_manager":{"address":"%s"}}`, uaddr)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("removing the last manager should fail")
+	}
+
+	// add user2 as a manager
+	msg = fmt.Sprintf(`{"add_manager":{"address":"%s"}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	res = GetContractConfig(t, ctx, juno, tfCoreContractAddr)
+	assert.Equal(t, len(res.Data.Managers), 2)
+
+	// user2 can now remove the original manager
+	msg = fmt.Sprintf(`{"remove_manager":{"address":"%s"}}`, uaddr)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	res = GetContractConfig(t, ctx, juno, tfCoreContractAddr)
+	assert.Equal(t, len(res.Data.Managers), 1)
+	assert.Equal(t, res.Data.Managers[0], uaddr2)
+
+	// and give it back
+	msg = fmt.Sprintf(`{"add_manager":{"address":"%s"}}`, uaddr)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+
+	// one step handover from user2, which must be confirmed and to a valid address
+	msg = fmt.Sprintf(`{"set_manager":{"new_manager":"%s","confirm_no_recovery":false}}`, uaddr)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("set_manager without confirmation should fail")
+	}
+	msg = `{"set_manager":{"new_manager":"juno1invalid","confirm_no_recovery":true}}`
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("set_manager to an invalid address should fail")
+	}
+	msg = fmt.Sprintf(`{"set_manager":{"new_manager":"%s","confirm_no_recovery":true}}`, uaddr)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreContractAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	res = GetContractConfig(t, ctx, juno, tfCoreContractAddr)
+	assert.Equal(t, len(res.Data.Managers), 1)
+	assert.Equal(t, res.Data.Managers[0], uaddr)
+
 	// DENOM WHITELIST
-	// adds a denom (Only allow factory/ in the future?)
-	msg = fmt.Sprintf(`{"add_denom":{"denoms":["%s"]}}`, "randomdenom")
+	// adds a denom (must be a factory/ denom)
+	randomDenom := fmt.Sprintf("factory/%s/randomdenom", uaddr)
+	msg = fmt.Sprintf(`{"add_denom":{"denoms":["%s"]}}`, randomDenom)
 	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
 		t.Fatal(err)
 	}
 
 	res = GetContractConfig(t, ctx, juno, tfCoreContractAddr)
 	assert.Assert(t, len(res.Data.Denoms) == 1)
+	assert.Equal(t, GetContractStats(t, ctx, juno, tfCoreContractAddr).Data.NumDenoms, uint32(1))
+
+	// adding it again is a no-op, unless strict
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "added"), "")
+	msg = fmt.Sprintf(`{"add_denom":{"denoms":["%s"],"strict":true}}`, randomDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("strict add_denom of a managed denom should fail")
+	}
+	assert.Assert(t, len(GetContractConfig(t, ctx, juno, tfCoreContractAddr).Data.Denoms) == 1)
 
 	// Remove denom
-	msg = fmt.Sprintf(`{"remove_denom":{"denoms":["%s"]}}`, "randomdenom")
+	msg = fmt.Sprintf(`{"remove_denom":{"denoms":["%s"]}}`, randomDenom)
 	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err != nil {
 		t.Fatal(err)
 	}
 
 	res = GetContractConfig(t, ctx, juno, tfCoreContractAddr)
 	assert.Assert(t, len(res.Data.Denoms) == 0)
+	assert.Equal(t, GetContractStats(t, ctx, juno, tfCoreContractAddr).Data.NumDenoms, uint32(0))
+
+	// with no denoms left every mint is rejected
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"1"}]}}`, uaddr, randomDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreContractAddr, msg); err == nil {
+		t.Fatal("minting with no managed denoms should fail")
+	}
 
 	// Create denom on instantiation
 	tfMsg := fmt.Sprintf(`{"allowed_mint_addresses":["%s"],"new_denoms":[{"name":"new","description":"desc","symbol":"crt","decimals":6,"initial_balances":[{"address":"%s","amount":"420"}]}]}`, uaddr, uaddr)
@@ -154,6 +830,17 @@ func TestBasicContract(t *testing.T) {
 	createdDenomAdmin := helpers.GetTokenFactoryAdmin(t, ctx, juno, tfCreatedDenom)
 	assert.Equal(t, tfCoreAddr, createdDenomAdmin)
 
+	// metadata set on creation is read back from the bank module
+	metadata, err := GetContractDenomMetadata(t, ctx, juno, tfCoreAddr, tfCreatedDenom)
+	require.NoError(t, err)
+	assert.Equal(t, metadata.Data.Name, "new")
+	assert.Equal(t, metadata.Data.Base, tfCreatedDenom)
+
+	// denoms which are not managed by this contract are rejected
+	if _, err := GetContractDenomMetadata(t, ctx, juno, tfCoreAddr, tfDenom); err == nil {
+		t.Fatal("metadata of an unmanaged denom should be rejected")
+	}
+
 	// Validate initial balances.
 	AssertBalance(t, ctx, juno, uaddr, tfCreatedDenom, 420)
 	// do the same thing but through the TF contract query
@@ -163,6 +850,326 @@ func TestBasicContract(t *testing.T) {
 	createdSupply := helpers.GetTokenFactorySupply(t, ctx, juno, tfCreatedDenom)
 	assert.Equal(t, createdSupply, "420")
 
+	// Create a denom and mint to user2 in the same transaction
+	// subdenoms the module would reject fail in the contract
+	for _, subdenom := range []string{"", "has space", "a/b", strings.Repeat("a", 45)} {
+		msg = fmt.Sprintf(`{"create_denom":{"subdenom":"%s"}}`, subdenom)
+		if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err == nil {
+			t.Fatalf("subdenom %q should be rejected", subdenom)
+		}
+	}
+
+	msg = fmt.Sprintf(`{"create_and_mint":{"subdenom":"fair","initial_mint":[["%s","1000"]]}}`, uaddr2)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	fairDenom := fmt.Sprintf(`factory/%s/fair`, tfCoreAddr)
+	// every mint has the display attribute, fair has no metadata so it is in base units
+	assert.Equal(t, GetWasmAttribute(txRes, "display"), fmt.Sprintf("1000:%s", fairDenom))
+	res = GetContractConfig(t, ctx, juno, tfCoreAddr)
+	assert.Equal(t, res.Data.Denoms[1], fairDenom)
+	AssertBalance(t, ctx, juno, uaddr2, fairDenom, 1000)
+
+	// the manager can mint without being whitelisted
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"set_whitelist":{"addresses":[]}}`); err != nil {
+		t.Fatal(err)
+	}
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"5"}]}}`, uaddr2, fairDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr2, fairDenom, 1005)
+
+	// but anyone else can not
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreAddr, msg); err == nil {
+		t.Fatal("minting as a non manager, non whitelisted address should fail")
+	}
+	AssertBalance(t, ctx, juno, uaddr2, fairDenom, 1005)
+
+	// DENOM MANAGERS
+	// user2 manages only the fair denom
+	msg = fmt.Sprintf(`{"set_denom_manager":{"denom":"%s","address":"%s"}}`, fairDenom, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+
+	// so it can grant minters of that denom
+	msg = fmt.Sprintf(`{"add_denom_minters":{"denom":"%s","addresses":["%s"]}}`, fairDenom, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+
+	// but not of other denoms, or the global whitelist
+	msg = fmt.Sprintf(`{"add_denom_minters":{"denom":"%s","addresses":["%s"]}}`, tfCreatedDenom, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreAddr, msg); err == nil {
+		t.Fatal("a denom manager should not manage other denoms")
+	}
+	msg = fmt.Sprintf(`{"add_whitelist":{"addresses":["%s"]}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreAddr, msg); err == nil {
+		t.Fatal("a denom manager should not manage the whitelist")
+	}
+
+	// errors name the invalid address
+	msg = fmt.Sprintf(`{"remove_denom":{"denoms":["%s"],"new_admin":"notanaddress"}}`, fairDenom)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg)
+	require.ErrorContains(t, err, `Invalid new_admin address: "notanaddress"`)
+	msg = fmt.Sprintf(`{"add_whitelist":{"addresses":["%s","notanaddress"]}}`, uaddr2)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg)
+	require.ErrorContains(t, err, `Invalid whitelist address: "notanaddress"`)
+
+	// removing a denom with a new admin also gives up control of it
+	msg = fmt.Sprintf(`{"remove_denom":{"denoms":["%s"],"new_admin":"%s"}}`, fairDenom, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, helpers.GetTokenFactoryAdmin(t, ctx, juno, fairDenom), uaddr2)
+	res = GetContractConfig(t, ctx, juno, tfCoreAddr)
+	assert.Equal(t, len(res.Data.Denoms), 1)
+
+	// the state left behind for a removed denom can be purged, but not while it is managed
+	assert.Equal(t, GetContractMintedTotal(t, ctx, juno, tfCoreAddr, fairDenom), "1005")
+	msg = fmt.Sprintf(`{"purge_denom_state":{"denom":"%s"}}`, tfCreatedDenom)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg)
+	require.ErrorContains(t, err, "still managed")
+	msg = fmt.Sprintf(`{"purge_denom_state":{"denom":"%s"}}`, fairDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetContractMintedTotal(t, ctx, juno, tfCoreAddr, fairDenom), "0")
+	assert.Assert(t, GetContractMaxSupply(t, ctx, juno, tfCoreAddr, fairDenom).Data == nil)
+
+	// COLLATERAL, every minted token must be backed 1:1 with the native denom
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"set_collateral_denom":{"denom":""}}`)
+	require.ErrorContains(t, err, "The collateral denom can not be empty")
+	msg = fmt.Sprintf(`{"set_collateral_denom":{"denom":"%s"}}`, tfCreatedDenom)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg)
+	require.ErrorContains(t, err, "The collateral denom can not be managed by this contract")
+	msg = fmt.Sprintf(`{"set_collateral_denom":{"denom":"%s"}}`, nativeDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	mintTen := fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"10"}]}}`, uaddr, tfCreatedDenom)
+
+	// under collateralized
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, mintTen, "--amount", fmt.Sprintf("9%s", nativeDenom)); err == nil {
+		t.Fatal("an under collateralized mint should fail")
+	}
+	AssertBalance(t, ctx, juno, uaddr, tfCreatedDenom, 420)
+
+	// exact
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, mintTen, "--amount", fmt.Sprintf("10%s", nativeDenom)); err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr, tfCreatedDenom, 430)
+	AssertBalance(t, ctx, juno, tfCoreAddr, nativeDenom, 10)
+
+	// over, the excess is refunded
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, mintTen, "--amount", fmt.Sprintf("15%s", nativeDenom)); err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, uaddr, tfCreatedDenom, 440)
+	AssertBalance(t, ctx, juno, tfCoreAddr, nativeDenom, 20)
+
+	// burning half the supply releases half the collateral
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"burn":{}}`, "--amount", fmt.Sprintf("220%s", tfCreatedDenom))
+	if err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, tfCoreAddr, nativeDenom, 10)
+	assert.Equal(t, GetWasmAttribute(txRes, "collateral_released"), fmt.Sprintf("10%s", nativeDenom))
+
+	// redemptions round down, 10 * 21 / 220 releases nothing and the dust stays held
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"burn":{}}`, "--amount", fmt.Sprintf("21%s", tfCreatedDenom))
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "collateral_released"), "")
+	AssertBalance(t, ctx, juno, tfCoreAddr, nativeDenom, 10)
+
+	// SWEEP funds sent to the contract by mistake, but never the collateral
+	stuck := ibc.WalletAmount{Address: tfCoreAddr, Denom: nativeDenom, Amount: math.NewInt(7)}
+	if err := juno.SendFunds(ctx, user.KeyName(), stuck); err != nil {
+		t.Fatal(err)
+	}
+	msg = fmt.Sprintf(`{"sweep":{"denom":"%s","to_address":"%s"}}`, nativeDenom, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	AssertBalance(t, ctx, juno, tfCoreAddr, nativeDenom, 10)
+
+	// managed denoms have to be burned instead
+	msg = fmt.Sprintf(`{"sweep":{"denom":"%s","to_address":"%s"}}`, tfCreatedDenom, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err == nil {
+		t.Fatal("sweeping a managed denom should fail")
+	}
+
+	// a denom can not be removed or given away while collateral is held for it, burns could no longer release it
+	msg = fmt.Sprintf(`{"remove_denom":{"denoms":["%s"]}}`, tfCreatedDenom)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg)
+	require.ErrorContains(t, err, "Collateral is still held")
+	msg = fmt.Sprintf(`{"transfer_admin":{"denom":"%s","new_address":"%s"}}`, tfCreatedDenom, uaddr)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg)
+	require.ErrorContains(t, err, "Collateral is still held")
+	assert.Equal(t, helpers.GetTokenFactoryAdmin(t, ctx, juno, tfCreatedDenom), tfCoreAddr)
+
+	// MINT TO SELF (still collateralized)
+	msg = fmt.Sprintf(`{"mint_to_self":{"denom":{"denom":"%s","amount":"5"}}}`, tfCreatedDenom)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg, "--amount", fmt.Sprintf("5%s", nativeDenom))
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "to_address"), tfCoreAddr)
+	AssertBalance(t, ctx, juno, tfCoreAddr, tfCreatedDenom, 5)
+	// crt was created with 6 decimals, which the display attribute is shown in
+	assert.Equal(t, GetWasmAttribute(txRes, "display"), "0.000005 crt")
+
+	// the contract's balances only list managed denoms it holds, not the collateral
+	assert.DeepEqual(t, GetContractBalances(t, ctx, juno, tfCoreAddr).Data, []Coin{{Denom: tfCreatedDenom, Amount: "5"}})
+
+	// the created denom is returned as the response data
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"create_denom":{"subdenom":"data"}}`)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var created struct {
+		Denom string `json:"denom"`
+	}
+	require.NoError(t, json.Unmarshal(GetContractResponseData(t, txRes), &created))
+	assert.Equal(t, created.Denom, fmt.Sprintf("factory/%s/data", tfCoreAddr))
+
+	// denoms created by this contract are told apart from ones transferred in
+	msg = fmt.Sprintf(`{"add_denom":{"denoms":["%s"]}}`, tfDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	origins := GetContractDenomsByOrigin(t, ctx, juno, tfCoreAddr)
+	assert.DeepEqual(t, origins.Data.SelfCreated, []string{tfCreatedDenom, created.Denom})
+	assert.DeepEqual(t, origins.Data.External, []string{tfDenom})
+
+	// a bad coin among good ones is named in the error
+	msg = fmt.Sprintf(`{"mint":{"address":"%s","denom":[{"denom":"%s","amount":"1"},{"denom":"%s","amount":"0"}]}}`, uaddr, tfCreatedDenom, created.Denom)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg, "--amount", fmt.Sprintf("1%s", nativeDenom))
+	require.ErrorContains(t, err, fmt.Sprintf("Invalid coin 0%q", created.Denom))
+
+	// ENSURE DENOMS creates a brand-new subdenom, which the module has no admin for yet
+	freshDenom := fmt.Sprintf("factory/%s/fresh", tfCoreAddr)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"ensure_denoms":{"subdenoms":["fresh"]}}`)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "created"), freshDenom)
+	assert.Equal(t, helpers.GetTokenFactoryAdmin(t, ctx, juno, freshDenom), tfCoreAddr)
+	assert.Equal(t, len(GetContractConfig(t, ctx, juno, tfCoreAddr).Data.Denoms), 4)
+
+	// it only creates the missing ones, so re-running it is a no-op
+
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"ensure_denoms":{"subdenoms":["crt","fresh"]}}`)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetWasmAttribute(txRes, "created"), "")
+	assert.Equal(t, len(GetContractConfig(t, ctx, juno, tfCoreAddr).Data.Denoms), 4)
+
+	// the admin of fair was given away above
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"ensure_denoms":{"subdenoms":["fair"]}}`); err == nil {
+		t.Fatal("ensuring a denom this contract is not the admin of should fail")
+	}
+
+	// MINT AND CALL sends the minted tokens along with a message to another contract.
+	// The other core contract does not manage crt, so its burn returns them to this one.
+	callMsg := base64.StdEncoding.EncodeToString([]byte(`{"burn":{}}`))
+	msg = fmt.Sprintf(`{"mint_and_call":{"contract":"%s","denom":{"denom":"%s","amount":"3"},"msg":"%s"}}`, tfCoreContractAddr, tfCreatedDenom, callMsg)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg, "--amount", fmt.Sprintf("3%s", nativeDenom))
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.DeepEqual(t, GetWasmAttributes(txRes, "method"), []string{"execute_mint", "execute_burn"})
+	assert.Equal(t, GetWasmAttribute(txRes, "call_contract"), tfCoreContractAddr)
+	AssertBalance(t, ctx, juno, tfCoreAddr, tfCreatedDenom, 8)
+
+	// only contracts can be called
+	msg = fmt.Sprintf(`{"mint_and_call":{"contract":"%s","denom":{"denom":"%s","amount":"3"},"msg":"%s"}}`, uaddr2, tfCreatedDenom, callMsg)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg, "--amount", fmt.Sprintf("3%s", nativeDenom)); err == nil {
+		t.Fatal("mint_and_call to an account should fail")
+	}
+
+	// RECLAIM ADMIN tracks a denom whose admin was given to the contract directly
+	reclaimDenom := helpers.CreateTokenFactoryDenom(t, ctx, juno, user, "reclaim")
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, fmt.Sprintf(`{"reclaim_admin":{"denom":"%s"}}`, reclaimDenom)); err == nil {
+		t.Fatal("reclaiming a denom the contract is not the admin of should fail")
+	}
+	helpers.TransferTokenFactoryAdmin(t, ctx, juno, user, tfCoreAddr, reclaimDenom)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, fmt.Sprintf(`{"reclaim_admin":{"denom":"%s"}}`, reclaimDenom))
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Assert(t, GetEventIndex(txRes, "change_admin", "new_admin", tfCoreAddr) >= 0)
+	assert.Assert(t, GetContractIsDenomMintable(t, ctx, juno, tfCoreAddr, reclaimDenom))
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, fmt.Sprintf(`{"reclaim_admin":{"denom":"%s"}}`, reclaimDenom)); err == nil {
+		t.Fatal("reclaiming a managed denom again should fail")
+	}
+
+	// RELINQUISH ALL hands every denom admin to a safe address and stops managing them,
+	// once no collateral is held for any of them
+	msg = fmt.Sprintf(`{"relinquish_all":{"new_admin":"%s","pause":true}}`, uaddr)
+	_, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg)
+	require.ErrorContains(t, err, "Collateral is still held")
+
+	// burning the whole supply releases the rest of the collateral
+	msg = fmt.Sprintf(`{"force_transfer":{"from":"%s","to":"%s","denom":{"denom":"%s","amount":"8"}}}`, tfCoreAddr, uaddr, tfCreatedDenom)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	crtBal, err := juno.GetBalance(ctx, uaddr, tfCreatedDenom)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"burn":{}}`, "--amount", fmt.Sprintf("%s%s", crtBal, tfCreatedDenom)); err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, helpers.GetTokenFactorySupply(t, ctx, juno, tfCreatedDenom), "0")
+
+	managed := GetContractConfig(t, ctx, juno, tfCoreAddr).Data.Denoms
+	assert.Assert(t, len(managed) > 0)
+	msg = fmt.Sprintf(`{"relinquish_all":{"new_admin":"%s","pause":true}}`, uaddr)
+	txRes, err = juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assert.Equal(t, GetEventCount(txRes, "change_admin"), len(managed))
+	for _, denom := range managed {
+		assert.Assert(t, GetEventIndex(txRes, "change_admin", "denom", denom) >= 0)
+	}
+	assert.Equal(t, len(GetContractConfig(t, ctx, juno, tfCoreAddr).Data.Denoms), 0)
+	assert.Assert(t, GetContractIsPaused(t, ctx, juno, tfCoreAddr))
+
+	// OWNERSHIP (cw-ownable interface)
+	msg = fmt.Sprintf(`{"update_ownership":{"transfer_ownership":{"new_owner":"%s","expiry":null}}}`, uaddr2)
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, msg); err != nil {
+		t.Fatal(err)
+	}
+	ownership := GetContractOwnership(t, ctx, juno, tfCoreAddr)
+	assert.Equal(t, *ownership.Data.Owner, uaddr)
+	assert.Equal(t, *ownership.Data.PendingOwner, uaddr2)
+
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreAddr, `{"update_ownership":"accept_ownership"}`); err != nil {
+		t.Fatal(err)
+	}
+	ownership = GetContractOwnership(t, ctx, juno, tfCoreAddr)
+	assert.Equal(t, *ownership.Data.Owner, uaddr2)
+	assert.Assert(t, ownership.Data.PendingOwner == nil)
+
+	// the previous owner can no longer renounce, the new one can
+	if _, err := juno.ExecuteContract(ctx, user.KeyName(), tfCoreAddr, `{"update_ownership":"renounce_ownership"}`); err == nil {
+		t.Fatal("renouncing as a non owner should fail")
+	}
+	if _, err := juno.ExecuteContract(ctx, user2.KeyName(), tfCoreAddr, `{"update_ownership":"renounce_ownership"}`); err != nil {
+		t.Fatal(err)
+	}
+	assert.Assert(t, GetContractOwnership(t, ctx, juno, tfCoreAddr).Data.Owner == nil)
+
 	// !important: debugging
 	// t.Log("GetHostRPCAddress", juno.GetHostRPCAddress())
 	// testutil.WaitForBlocks(ctx, 20_000, juno)
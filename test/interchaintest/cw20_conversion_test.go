@@ -56,7 +56,7 @@ func TestCw20ConversionMigrateContract(t *testing.T) {
 
 	// Ensure the contract config data is set correctly.
 	res := GetContractConfig(t, ctx, juno, tfCoreContractAddr)
-	assert.Equal(t, res.Data.AllowedMintAddresses[0], cw20MigrateContractAddr)
+	assert.Equal(t, GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data[0], cw20MigrateContractAddr)
 	assert.Equal(t, res.Data.Denoms[0], tfDenom)
 
 	// actual CW20 testing on the contract
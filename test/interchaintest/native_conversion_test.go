@@ -59,7 +59,7 @@ func TestNativeConversionMigrateContract(t *testing.T) {
 
 	// Ensure the contract config data is set correctly.
 	res := GetContractConfig(t, ctx, juno, tfCoreContractAddr)
-	assert.Equal(t, res.Data.AllowedMintAddresses[0], naitveMigrateContractAddr)
+	assert.Equal(t, GetContractWhitelist(t, ctx, juno, tfCoreContractAddr).Data[0], naitveMigrateContractAddr)
 	assert.Equal(t, res.Data.Denoms[0], tfDenom)
 
 	// ensure user has 0 tf denom balance
@@ -2,8 +2,11 @@ package test
 
 import (
 	"context"
+	"encoding/hex"
 	"testing"
 
+	wasmtypes "github.com/CosmWasm/wasmd/x/wasm/types"
+	sdk "github.com/cosmos/cosmos-sdk/types"
 	"github.com/strangelove-ventures/interchaintest/v7/chain/cosmos"
 	"github.com/stretchr/testify/require"
 	"gotest.tools/assert"
@@ -27,6 +30,182 @@ func GetContractConfig(t *testing.T, ctx context.Context, chain *cosmos.CosmosCh
 	return cRes
 }
 
+func GetContractConfigFields(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract string, fields ...string) GetConfigFieldsResponse {
+	var cRes GetConfigFieldsResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetConfigFields: &GetConfigFields{Fields: fields}}, &cRes)
+	require.NoError(t, err)
+	return cRes
+}
+
+func GetContractWhitelist(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract string) GetWhitelistResponse {
+	var wRes GetWhitelistResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetWhitelist: &GetWhitelist{}}, &wRes)
+	require.NoError(t, err)
+	t.Log("GetContractWhitelist", wRes.Data)
+	return wRes
+}
+
+func GetContractMaxSupply(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract, denom string) GetMaxSupplyResponse {
+	var mRes GetMaxSupplyResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetMaxSupply: &GetMaxSupply{Denom: denom}}, &mRes)
+	require.NoError(t, err)
+	return mRes
+}
+
+func GetContractConfigPage(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract, section string, startAfter *string, limit uint32) []string {
+	var pRes GetWhitelistResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetConfigPage: &GetConfigPage{Section: section, StartAfter: startAfter, Limit: &limit}}, &pRes)
+	require.NoError(t, err)
+	return pRes.Data
+}
+
+func GetContractMintedTotal(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract, denom string) string {
+	var mRes GetMintedTotalResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetMintedTotal: &GetMaxSupply{Denom: denom}}, &mRes)
+	require.NoError(t, err)
+	return mRes.Data
+}
+
+// GetContractDenomMetadata returns the query error instead of failing, as unmanaged denoms are rejected
+func GetContractDenomMetadata(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract, denom string) (GetDenomMetadataResponse, error) {
+	var mRes GetDenomMetadataResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetDenomMetadata: &GetDenomMetadata{Denom: denom}}, &mRes)
+	return mRes, err
+}
+
+func GetContractDenomsByOrigin(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract string) GetDenomsByOriginResponse {
+	var dRes GetDenomsByOriginResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetDenomsByOrigin: &struct{}{}}, &dRes)
+	require.NoError(t, err)
+	return dRes
+}
+
+func GetContractOwnership(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract string) OwnershipResponse {
+	var oRes OwnershipResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{Ownership: &struct{}{}}, &oRes)
+	require.NoError(t, err)
+	return oRes
+}
+
+func GetContractMintAllowance(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract, denom string) GetMintAllowanceResponse {
+	var aRes GetMintAllowanceResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetMintAllowance: &GetMaxSupply{Denom: denom}}, &aRes)
+	require.NoError(t, err)
+	return aRes
+}
+
+func GetContractBalances(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract string) GetAllBalancesResponse {
+	var bRes GetAllBalancesResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetContractBalances: &struct{}{}}, &bRes)
+	require.NoError(t, err)
+	return bRes
+}
+
+func GetContractIsPaused(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract string) bool {
+	var pRes IsPausedResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{IsPaused: &struct{}{}}, &pRes)
+	require.NoError(t, err)
+	return pRes.Data.Paused
+}
+
+func GetContractIsDenomMintable(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract, denom string) bool {
+	var mRes IsDenomMintableResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{IsDenomMintable: &GetMaxSupply{Denom: denom}}, &mRes)
+	require.NoError(t, err)
+	return mRes.Data.Mintable
+}
+
+func GetContractStats(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract string) GetStatsResponse {
+	var sRes GetStatsResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{GetStats: &struct{}{}}, &sRes)
+	require.NoError(t, err)
+	return sRes
+}
+
+func GetContractSimulateBurn(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract string, funds []Coin) SimulateBurnResponse {
+	var sRes SimulateBurnResponse
+	err := chain.QueryContract(ctx, contract, QueryMsg{SimulateBurn: &SimulateBurn{Funds: funds}}, &sRes)
+	require.NoError(t, err)
+	return sRes
+}
+
+// GetContractSimulateMint previews a mint from sender, the error is returned rather than failing the test
+func GetContractSimulateMint(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract, sender, address string, denoms []Coin) SimulateMintResponse {
+	var sRes SimulateMintResponse
+	sRes.Err = chain.QueryContract(ctx, contract, QueryMsg{SimulateMint: &SimulateMint{Sender: sender, Address: address, Denoms: denoms}}, &sRes)
+	return sRes
+}
+
+// GetContractResponseData returns the data set by the contract in the response of a single execute
+func GetContractResponseData(t *testing.T, res *sdk.TxResponse) []byte {
+	bz, err := hex.DecodeString(res.Data)
+	require.NoError(t, err)
+
+	var msgData sdk.TxMsgData
+	require.NoError(t, msgData.Unmarshal(bz))
+	require.Len(t, msgData.MsgResponses, 1)
+
+	var execRes wasmtypes.MsgExecuteContractResponse
+	require.NoError(t, execRes.Unmarshal(msgData.MsgResponses[0].Value))
+	return execRes.Data
+}
+
+// GetWasmAttribute returns the value of a contract response attribute in a transaction
+func GetWasmAttribute(res *sdk.TxResponse, key string) string {
+	for _, event := range res.Events {
+		if event.Type != "wasm" {
+			continue
+		}
+		for _, attr := range event.Attributes {
+			if attr.Key == key {
+				return attr.Value
+			}
+		}
+	}
+	return ""
+}
+
+// GetWasmAttributes returns every value of a contract response attribute in a transaction, in execution order
+func GetWasmAttributes(res *sdk.TxResponse, key string) []string {
+	var values []string
+	for _, event := range res.Events {
+		if event.Type != "wasm" {
+			continue
+		}
+		for _, attr := range event.Attributes {
+			if attr.Key == key {
+				values = append(values, attr.Value)
+			}
+		}
+	}
+	return values
+}
+
+// GetEventIndex returns the position of the first event of a type with the attribute, -1 if there is none
+func GetEventIndex(res *sdk.TxResponse, eventType, key, value string) int {
+	for i, event := range res.Events {
+		if event.Type != eventType {
+			continue
+		}
+		for _, attr := range event.Attributes {
+			if attr.Key == key && attr.Value == value {
+				return i
+			}
+		}
+	}
+	return -1
+}
+
+func GetEventCount(res *sdk.TxResponse, eventType string) int {
+	count := 0
+	for _, event := range res.Events {
+		if event.Type == eventType {
+			count++
+		}
+	}
+	return count
+}
+
 // TokenFactory Core contract Queries
 func GetCoreContractUserBalance(t *testing.T, ctx context.Context, chain *cosmos.CosmosChain, contract, uaddr, tfDenom string) GetBalanceResponse {
 	var bRes GetBalanceResponse
@@ -9,12 +9,60 @@ package test
 // EntryPoint
 type QueryMsg struct {
 	// GetEntries   *GetEntries `json:"get_entries,omitempty"`
-	GetConfig      *struct{}       `json:"get_config,omitempty"`
-	GetBalance     *GetBalance     `json:"get_balance,omitempty"`
-	GetAllBalances *GetAllBalances `json:"get_all_balances,omitempty"`
+	GetConfig           *struct{}         `json:"get_config,omitempty"`
+	GetConfigFields     *GetConfigFields  `json:"get_config_fields,omitempty"`
+	GetConfigPage       *GetConfigPage    `json:"get_config_page,omitempty"`
+	GetWhitelist        *GetWhitelist     `json:"get_whitelist,omitempty"`
+	GetMaxSupply        *GetMaxSupply     `json:"get_max_supply,omitempty"`
+	GetMintedTotal      *GetMaxSupply     `json:"get_minted_total,omitempty"`
+	SimulateMint        *SimulateMint     `json:"simulate_mint,omitempty"`
+	SimulateBurn        *SimulateBurn     `json:"simulate_burn,omitempty"`
+	GetStats            *struct{}         `json:"get_stats,omitempty"`
+	GetDenomMetadata    *GetDenomMetadata `json:"get_denom_metadata,omitempty"`
+	Ownership           *struct{}         `json:"ownership,omitempty"`
+	GetDenomsByOrigin   *struct{}         `json:"get_denoms_by_origin,omitempty"`
+	GetMintAllowance    *GetMaxSupply     `json:"get_mint_allowance,omitempty"`
+	IsPaused            *struct{}         `json:"is_paused,omitempty"`
+	IsDenomMintable     *GetMaxSupply     `json:"is_denom_mintable,omitempty"`
+	GetBalance          *GetBalance       `json:"get_balance,omitempty"`
+	GetAllBalances      *GetAllBalances   `json:"get_all_balances,omitempty"`
+	GetContractBalances *struct{}         `json:"get_contract_balances,omitempty"`
 }
 
 // entry helpers
+type GetConfigFields struct {
+	Fields []string `json:"fields"`
+}
+
+type GetWhitelist struct {
+	StartAfter *string `json:"start_after,omitempty"`
+	Limit      *uint32 `json:"limit,omitempty"`
+}
+
+type GetConfigPage struct {
+	Section    string  `json:"section"`
+	StartAfter *string `json:"start_after,omitempty"`
+	Limit      *uint32 `json:"limit,omitempty"`
+}
+
+type GetMaxSupply struct {
+	Denom string `json:"denom"`
+}
+
+type GetDenomMetadata struct {
+	Denom string `json:"denom"`
+}
+
+type SimulateBurn struct {
+	Funds []Coin `json:"funds"`
+}
+
+type SimulateMint struct {
+	Sender  string `json:"sender"`
+	Address string `json:"address"`
+	Denoms  []Coin `json:"denoms"`
+}
+
 type GetBalance struct {
 	Address string `json:"address"`
 	Denom   string `json:"denom"`
@@ -24,6 +72,16 @@ type GetAllBalances struct {
 	Address string `json:"address"`
 }
 
+// Signed for mint_with_voucher, the field order must match tokenfactory_core/src/msg.rs
+type Voucher struct {
+	Contract  string `json:"contract"`
+	Recipient string `json:"recipient"`
+	Denom     string `json:"denom"`
+	Amount    string `json:"amount"`
+	Expiry    string `json:"expiry"`
+	Nonce     string `json:"nonce"`
+}
+
 // Response Types (json is always 'data' from the chain return value)
 type GetConfigResponse struct {
 	Data *ConfigTfCore `json:"data"`
@@ -37,9 +95,91 @@ type GetAllBalancesResponse struct {
 	Data []Coin `json:"data"`
 }
 
-// type WhitelistResponse struct {
-// 	Data []string `json:"data"`
-// }
+type GetWhitelistResponse struct {
+	Data []string `json:"data"`
+}
+
+type GetMaxSupplyResponse struct {
+	Data *string `json:"data"`
+}
+
+type GetMintedTotalResponse struct {
+	Data string `json:"data"`
+}
+
+type GetDenomMetadataResponse struct {
+	Data *struct {
+		Name    string `json:"name"`
+		Symbol  string `json:"symbol"`
+		Base    string `json:"base"`
+		Display string `json:"display"`
+	} `json:"data"`
+}
+
+type GetDenomsByOriginResponse struct {
+	Data *struct {
+		SelfCreated []string `json:"self_created"`
+		External    []string `json:"external"`
+	} `json:"data"`
+}
+
+type OwnershipResponse struct {
+	Data *struct {
+		Owner        *string `json:"owner"`
+		PendingOwner *string `json:"pending_owner"`
+	} `json:"data"`
+}
+
+type GetConfigFieldsResponse struct {
+	Data *struct {
+		Managers  []string `json:"managers"`
+		Denoms    []string `json:"denoms"`
+		Whitelist []string `json:"whitelist"`
+	} `json:"data"`
+}
+
+type GetMintAllowanceResponse struct {
+	Data *struct {
+		Remaining string `json:"remaining"`
+		ResetsAt  string `json:"resets_at"`
+	} `json:"data"`
+}
+
+type IsPausedResponse struct {
+	Data *struct {
+		Paused bool `json:"paused"`
+	} `json:"data"`
+}
+
+type IsDenomMintableResponse struct {
+	Data *struct {
+		Mintable bool `json:"mintable"`
+	} `json:"data"`
+}
+
+type GetStatsResponse struct {
+	Data *struct {
+		NumWhitelisted uint32 `json:"num_whitelisted"`
+		NumDenoms      uint32 `json:"num_denoms"`
+	} `json:"data"`
+}
+
+type SimulateBurnResponse struct {
+	Data *struct {
+		ToBurn   []Coin `json:"to_burn"`
+		ToReturn []Coin `json:"to_return"`
+	} `json:"data"`
+}
+
+type SimulateMintResponse struct {
+	Data *struct {
+		Address string `json:"address"`
+		Total   []Coin `json:"total"`
+		Fee     *Coin  `json:"fee"`
+	} `json:"data"`
+	// set when the mint would fail
+	Err error `json:"-"`
+}
 
 // // Middleware
 // type GetEntries struct {
@@ -48,9 +188,8 @@ type GetAllBalancesResponse struct {
 
 // Base Data Types
 type ConfigTfCore struct {
-	Manager              string   `json:"manager"`
-	AllowedMintAddresses []string `json:"allowed_mint_addresses"`
-	Denoms               []string `json:"denoms"`
+	Managers []string `json:"managers"`
+	Denoms   []string `json:"denoms"`
 }
 
 type Coin struct {